@@ -0,0 +1,213 @@
+use super::solver::Part;
+use super::Solution;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The registry of expected answers, relative to the working directory.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Verdict {
+    Correct,
+    Wrong(String),
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Verdict::Correct => write!(f, "✔"),
+            Verdict::Wrong(expected) => write!(f, "✘ (expected {})", expected),
+            Verdict::Unknown => Ok(()),
+        }
+    }
+}
+
+/// Expected answers keyed by year, day and part, stored as a small TOML file
+/// and parsed back into `Solution`s, so `5` matches a `u64` and a `usize` alike:
+///
+/// ```toml
+/// [2023.day05]
+/// part1 = "486613012"
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    entries: BTreeMap<(u16, u8, u8), Solution>,
+}
+
+fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+impl Answers {
+    /// Loads the registry; a missing file is an empty registry.
+    pub fn load(path: &Path) -> io::Result<Answers> {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents.parse(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&Solution> {
+        self.entries.get(&(year, day, part_number(part)))
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: Part, answer: &Solution) {
+        self.entries.insert((year, day, part_number(part)), answer.clone());
+    }
+
+    pub fn check(&self, year: u16, day: u8, part: Part, answer: &Solution) -> Verdict {
+        match self.get(year, day, part) {
+            Some(expected) if expected == answer => Verdict::Correct,
+            // A list recorded as text reads back as a string.
+            Some(expected) if expected.to_string() == answer.to_string() => Verdict::Correct,
+            Some(expected) => Verdict::Wrong(expected.to_string()),
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        let mut section = None;
+        for (index, line) in s.lines().enumerate() {
+            let line = line.trim();
+            let invalid = || {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("line {}: invalid entry {:?}", index + 1, line),
+                )
+            };
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let (year, day) = header.split_once(".day").ok_or_else(invalid)?;
+                let year = year.parse::<u16>().map_err(|_| invalid())?;
+                let day = day.parse::<u8>().map_err(|_| invalid())?;
+                section = Some((year, day));
+                continue;
+            }
+            let (part, value) = parse_entry(line).ok_or_else(invalid)?;
+            let (year, day) = section.ok_or_else(invalid)?;
+            answers.entries.insert((year, day, part_number(part)), value);
+        }
+        Ok(answers)
+    }
+}
+
+/// Parses a `part1 = "value"` line; the quotes are optional.
+pub fn parse_entry(line: &str) -> Option<(Part, Solution)> {
+    let (key, value) = line.split_once('=')?;
+    let part = match key.trim() {
+        "part1" => Part::One,
+        "part2" => Part::Two,
+        _ => return None,
+    };
+    let value = value.trim();
+    let value = match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        Some(quoted) => unescape(quoted),
+        None => value.to_string(),
+    };
+    let Ok(value) = value.parse::<Solution>();
+    Some((part, value))
+}
+
+/// Undoes the escapes written by `Display`, so multi-line answers such as
+/// grids survive a round trip.
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('r') => out.push('\r'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut current = None;
+        for (&(year, day, part), answer) in &self.entries {
+            if current != Some((year, day)) {
+                if current.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[{}.day{:02}]", year, day)?;
+                current = Some((year, day));
+            }
+            writeln!(f, "part{} = {:?}", part, answer.to_string())?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_parse_answers() {
+    let answers: Answers = "# comment\n[2023.day03]\npart1 = \"554003\"\n\n[2022.day05]\npart2 = 46\n"
+        .parse()
+        .unwrap();
+    assert_eq!(Some(&Solution::U64(554003)), answers.get(2023, 3, Part::One));
+    assert_eq!(Some(&Solution::Usize(46)), answers.get(2022, 5, Part::Two));
+    assert_eq!(None, answers.get(2023, 5, Part::Two));
+    assert_eq!(None, answers.get(2022, 5, Part::One));
+}
+
+#[test]
+fn test_invalid_answers() {
+    assert!("part1 = \"1\"".parse::<Answers>().is_err());
+    assert!("[2023.day03]\npart3 = \"1\"".parse::<Answers>().is_err());
+    assert!("[2023.dayx]".parse::<Answers>().is_err());
+    assert!("[day03]".parse::<Answers>().is_err());
+}
+
+#[test]
+fn test_record_and_check() {
+    let mut answers = Answers::default();
+    answers.record(2023, 7, Part::Two, &Solution::from(5905u64));
+    answers.record(2023, 7, Part::One, &Solution::from("abc"));
+    assert_eq!(Verdict::Correct, answers.check(2023, 7, Part::Two, &Solution::from(5905usize)));
+    assert_eq!(
+        Verdict::Wrong("5905".to_string()),
+        answers.check(2023, 7, Part::Two, &Solution::from(1u8))
+    );
+    assert_eq!(Verdict::Unknown, answers.check(2023, 8, Part::One, &Solution::from(1u8)));
+    assert_eq!(Verdict::Unknown, answers.check(2022, 7, Part::One, &Solution::from("abc")));
+    assert_eq!("[2023.day07]\npart1 = \"abc\"\npart2 = \"5905\"\n", answers.to_string());
+    assert_eq!(answers, answers.to_string().parse().unwrap());
+
+    let grid = Solution::from(vec![vec!['#', '"'], vec!['\\', '.']]);
+    answers.record(2023, 10, Part::One, &grid);
+    let list = Solution::from(vec![Solution::from("a"), Solution::from(2u8)]);
+    answers.record(2023, 10, Part::Two, &list);
+    let parsed: Answers = answers.to_string().parse().unwrap();
+    assert_eq!(Verdict::Correct, parsed.check(2023, 10, Part::One, &grid));
+    assert_eq!(Verdict::Correct, parsed.check(2023, 10, Part::Two, &list));
+}
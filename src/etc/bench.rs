@@ -0,0 +1,187 @@
+use super::solver::{DayRunner, Part, PartRun};
+use super::SolveError;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+pub const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Some(Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean as u64),
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+        })
+    }
+}
+
+/// Stats for each stage that was run, keyed by the names in `STAGES`.
+pub type DayBench = Vec<(&'static str, Stats)>;
+
+/// Runs a day `warmup` times without measuring, then `samples` more times,
+/// collecting the timings of every stage.
+pub fn bench_day(
+    runner: DayRunner,
+    input: &str,
+    part: Option<Part>,
+    warmup: usize,
+    samples: usize,
+) -> Result<DayBench, SolveError> {
+    for _ in 0..warmup {
+        runner(input, part)?;
+    }
+
+    let mut timings: [Vec<Duration>; 3] = Default::default();
+    for _ in 0..samples {
+        let run = runner(input, part)?;
+        timings[0].push(run.parse);
+        for (index, part_run) in [(1, run.part1), (2, run.part2)] {
+            if let Some(PartRun { answer, elapsed }) = part_run {
+                answer?;
+                timings[index].push(elapsed);
+            }
+        }
+    }
+
+    Ok(STAGES
+        .iter()
+        .zip(&timings)
+        .filter_map(|(&stage, samples)| Some((stage, Stats::from_samples(samples)?)))
+        .collect())
+}
+
+/// Median timings from a previous benchmark, stored one `year day stage nanos`
+/// entry per line.
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+    entries: BTreeMap<(u16, u8, String), Duration>,
+}
+
+impl Baseline {
+    /// Loads a baseline file; a missing file is an empty baseline.
+    pub fn load(path: &Path) -> io::Result<Baseline> {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents.parse(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Baseline::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, year: u16, day: u8, stage: &str) -> Option<Duration> {
+        self.entries.get(&(year, day, stage.to_string())).copied()
+    }
+
+    pub fn insert(&mut self, year: u16, day: u8, stage: &str, median: Duration) {
+        self.entries.insert((year, day, stage.to_string()), median);
+    }
+}
+
+impl std::str::FromStr for Baseline {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut baseline = Baseline::default();
+        for line in s.lines().filter(|line| !line.trim().is_empty()) {
+            let invalid = || {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid baseline entry: {:?}", line),
+                )
+            };
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [year, day, stage, nanos] = fields[..] else {
+                return Err(invalid());
+            };
+            let year = year.parse().map_err(|_| invalid())?;
+            let day = day.parse().map_err(|_| invalid())?;
+            let nanos = nanos.parse().map_err(|_| invalid())?;
+            baseline.insert(year, day, stage, Duration::from_nanos(nanos));
+        }
+        Ok(baseline)
+    }
+}
+
+impl std::fmt::Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for ((year, day, stage), median) in &self.entries {
+            writeln!(f, "{} {:02} {} {}", year, day, stage, median.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+/// Relative change from `old` to `new` in percent, or `None` if `old` is zero
+/// and no change can be given relative to it.
+pub fn percent_change(old: Duration, new: Duration) -> Option<f64> {
+    if old.is_zero() {
+        return None;
+    }
+    let old = old.as_nanos() as f64;
+    Some((new.as_nanos() as f64 - old) / old * 100.0)
+}
+
+#[test]
+fn test_stats() {
+    let samples: Vec<Duration> = [4, 1, 3, 2].iter().map(|&ms| Duration::from_millis(ms)).collect();
+    let stats = Stats::from_samples(&samples).unwrap();
+    assert_eq!(Duration::from_millis(1), stats.min);
+    assert_eq!(Duration::from_micros(2500), stats.median);
+    assert_eq!(Duration::from_micros(2500), stats.mean);
+    assert_eq!(1118, stats.stddev.as_micros());
+    assert_eq!(None, Stats::from_samples(&[]));
+}
+
+#[test]
+fn test_baseline_round_trip() {
+    let mut baseline = Baseline::default();
+    baseline.insert(2023, 5, "parse", Duration::from_nanos(1200));
+    baseline.insert(2023, 12, "part2", Duration::from_nanos(99));
+    baseline.insert(2024, 12, "part2", Duration::from_nanos(150));
+    assert!(baseline.to_string().starts_with("2023 05 parse 1200\n"));
+    let parsed: Baseline = baseline.to_string().parse().unwrap();
+    assert_eq!(baseline, parsed);
+    assert_eq!(Some(Duration::from_nanos(99)), parsed.get(2023, 12, "part2"));
+    assert_eq!(Some(Duration::from_nanos(150)), parsed.get(2024, 12, "part2"));
+    assert_eq!(None, parsed.get(2022, 12, "part2"));
+    assert!("05 parse 1200".parse::<Baseline>().is_err());
+}
+
+#[test]
+fn test_percent_change() {
+    let change = percent_change(Duration::from_millis(200), Duration::from_millis(150)).unwrap();
+    assert!((change + 25.0).abs() < 1e-9);
+    assert_eq!(None, percent_change(Duration::ZERO, Duration::from_millis(1)));
+}
@@ -0,0 +1,372 @@
+use super::report::Format;
+use super::solver::Part;
+use std::fmt::{Display, Formatter, Result};
+use std::path::PathBuf;
+
+pub const FIRST_DAY: u8 = 1;
+pub const LAST_DAY: u8 = 25;
+/// Year used when `--year` is not given.
+pub const DEFAULT_YEAR: u16 = 2023;
+const FIRST_YEAR: u16 = 2015;
+
+pub const USAGE: &str = "\
+Usage: advent_of_code_template [bench|list|new] [OPTIONS] <DAYS>...
+       advent_of_code_template submit [--year <YYYY>] <DAY> <PART>
+
+Commands:
+  bench      time every stage over many runs and report statistics
+  list       show the registered days and which of them are still stubs
+  new        create the module, example input and registration of one day
+  submit     post the answer of one part and record the response in submissions.txt
+
+Days:
+  5          a single day
+  1-5        an inclusive range of days
+  all        every day from 1 to 25
+
+Options:
+  -y, --year <YYYY>    the event year to run (default 2023)
+  -p, --part <1|2>     only run the given part
+  -i, --input <PATH>   read the input from PATH (single day only)
+  -e, --example        use the example input instead of the puzzle input
+  -r, --repeat <N>     solve each day N times and report the mean time
+  -q, --quiet          only print the answers
+      --parallel       solve the days concurrently, still reporting in day order
+      --record         save the answers as the expected ones in answers.toml
+  -f, --format <FMT>   output format: human (default), json, csv or markdown
+  -h, --help           print this message
+
+Bench options:
+  --warmup <N>         untimed runs before sampling (default 3)
+  --samples <N>        timed runs per day (default 20)
+  --baseline <PATH>    compare against the medians saved in PATH, then
+                       save the new medians to it";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Run,
+    Bench,
+    List,
+    New,
+    Submit,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Options {
+    pub command: Command,
+    pub year: u16,
+    pub days: Vec<u8>,
+    pub part: Option<Part>,
+    pub input: Option<PathBuf>,
+    pub example: bool,
+    pub repeat: usize,
+    pub quiet: bool,
+    pub parallel: bool,
+    pub record: bool,
+    pub format: Format,
+    pub warmup: usize,
+    pub samples: usize,
+    pub baseline: Option<PathBuf>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            command: Command::Run,
+            year: DEFAULT_YEAR,
+            days: Vec::new(),
+            part: None,
+            input: None,
+            example: false,
+            repeat: 1,
+            quiet: false,
+            parallel: false,
+            record: false,
+            format: Format::Human,
+            warmup: 3,
+            samples: 20,
+            baseline: None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CliError {
+    Help,
+    Invalid(String),
+}
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            CliError::Help => write!(f, "{}", USAGE),
+            CliError::Invalid(msg) => write!(f, "error: {}\n\n{}", msg, USAGE),
+        }
+    }
+}
+
+fn invalid(msg: impl Into<String>) -> CliError {
+    CliError::Invalid(msg.into())
+}
+
+/// Parses the command-line arguments, excluding the program name.
+pub fn parse_args<I>(args: I) -> std::result::Result<Options, CliError>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut options = Options::default();
+    let mut args = args.into_iter().peekable();
+
+    match args.peek().map(String::as_str) {
+        Some("bench") => options.command = Command::Bench,
+        Some("list") => options.command = Command::List,
+        Some("new") => options.command = Command::New,
+        Some("submit") => options.command = Command::Submit,
+        _ => {}
+    }
+    if options.command != Command::Run {
+        args.next();
+    }
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| invalid(format!("{} expects a value", name)))
+        };
+        match arg.as_str() {
+            "-h" | "--help" => return Err(CliError::Help),
+            "-y" | "--year" => options.year = parse_year(&value(&arg)?)?,
+            "-p" | "--part" => options.part = Some(parse_part(&value(&arg)?)?),
+            "-i" | "--input" => options.input = Some(PathBuf::from(value(&arg)?)),
+            "-e" | "--example" => options.example = true,
+            "-r" | "--repeat" => options.repeat = parse_count(&arg, &value(&arg)?, 1)?,
+            "-q" | "--quiet" => options.quiet = true,
+            "--parallel" => options.parallel = true,
+            "--record" => options.record = true,
+            "-f" | "--format" => options.format = parse_format(&value(&arg)?)?,
+            "--warmup" => options.warmup = parse_count(&arg, &value(&arg)?, 0)?,
+            "--samples" => options.samples = parse_count(&arg, &value(&arg)?, 1)?,
+            "--baseline" => options.baseline = Some(PathBuf::from(value(&arg)?)),
+            _ if arg.starts_with('-') => return Err(invalid(format!("unknown option {}", arg))),
+            // `submit <day> <part>`: the second positional argument is the part.
+            _ if options.command == Command::Submit && !options.days.is_empty() => {
+                if options.part.is_some() {
+                    return Err(invalid("submit takes one day and one part"));
+                }
+                options.part = Some(parse_part(&arg)?);
+            }
+            _ => options.days.extend(parse_days(&arg)?),
+        }
+    }
+
+    if options.command == Command::List && options.days.is_empty() {
+        options.days = (FIRST_DAY..=LAST_DAY).collect();
+    }
+    if matches!(options.command, Command::List | Command::New | Command::Submit) {
+        let defaults = Options {
+            command: options.command,
+            year: options.year,
+            days: options.days.clone(),
+            part: options.part,
+            ..Options::default()
+        };
+        if options != defaults || (options.part.is_some() && options.command != Command::Submit) {
+            return Err(invalid("list, new and submit only take days and --year"));
+        }
+    }
+    if options.command == Command::New && options.days.len() > 1 {
+        return Err(invalid("new creates a single day"));
+    }
+    if options.command == Command::Submit && (options.days.len() != 1 || options.part.is_none()) {
+        return Err(invalid("submit takes one day and one part"));
+    }
+    if options.days.is_empty() {
+        return Err(invalid("no days given"));
+    }
+    if options.input.is_some() && options.days.len() > 1 {
+        return Err(invalid("--input can only be used with a single day"));
+    }
+    if options.input.is_some() && options.example {
+        return Err(invalid("--input and --example cannot be combined"));
+    }
+    if options.record && (options.input.is_some() || options.example) {
+        return Err(invalid("--record only applies to the puzzle inputs"));
+    }
+    if options.record && options.command != Command::Run {
+        return Err(invalid("--record cannot be used with bench"));
+    }
+    if options.format != Format::Human && options.command != Command::Run {
+        return Err(invalid("--format cannot be used with bench"));
+    }
+    if options.parallel && options.command != Command::Run {
+        return Err(invalid("--parallel cannot be used with bench"));
+    }
+    if options.repeat != 1 && options.command != Command::Run {
+        return Err(invalid("--repeat cannot be used with bench, use --samples"));
+    }
+    if options.quiet && options.command != Command::Run {
+        return Err(invalid("--quiet cannot be used with bench"));
+    }
+    if options.baseline.is_some() && options.command != Command::Bench {
+        return Err(invalid("--baseline can only be used with bench"));
+    }
+    Ok(options)
+}
+
+fn parse_count(name: &str, s: &str, min: usize) -> std::result::Result<usize, CliError> {
+    match s.parse() {
+        Ok(n) if n >= min => Ok(n),
+        _ => Err(invalid(format!("{} expects a number of at least {}", name, min))),
+    }
+}
+
+fn parse_format(s: &str) -> std::result::Result<Format, CliError> {
+    match s {
+        "human" => Ok(Format::Human),
+        "json" => Ok(Format::Json),
+        "csv" => Ok(Format::Csv),
+        "markdown" | "md" => Ok(Format::Markdown),
+        _ => Err(invalid(format!("not a valid format: {}", s))),
+    }
+}
+
+fn parse_year(s: &str) -> std::result::Result<u16, CliError> {
+    match s.parse::<u16>() {
+        Ok(year) if year >= FIRST_YEAR => Ok(year),
+        _ => Err(invalid(format!("not a valid year: {}", s))),
+    }
+}
+
+fn parse_part(s: &str) -> std::result::Result<Part, CliError> {
+    match s {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(invalid(format!("not a valid part: {}", s))),
+    }
+}
+
+fn parse_day(s: &str) -> std::result::Result<u8, CliError> {
+    match s.parse::<u8>() {
+        Ok(day) if (FIRST_DAY..=LAST_DAY).contains(&day) => Ok(day),
+        _ => Err(invalid(format!(
+            "not a valid day: {} (expected {}-{})",
+            s, FIRST_DAY, LAST_DAY
+        ))),
+    }
+}
+
+fn parse_days(s: &str) -> std::result::Result<Vec<u8>, CliError> {
+    if s == "all" {
+        return Ok((FIRST_DAY..=LAST_DAY).collect());
+    }
+    match s.split_once('-') {
+        Some((first, last)) => {
+            let (first, last) = (parse_day(first)?, parse_day(last)?);
+            if first > last {
+                return Err(invalid(format!("empty day range: {}", s)));
+            }
+            Ok((first..=last).collect())
+        }
+        None => Ok(vec![parse_day(s)?]),
+    }
+}
+
+#[cfg(test)]
+fn args(s: &str) -> Vec<String> {
+    s.split_whitespace().map(String::from).collect()
+}
+
+#[test]
+fn test_parse_days() {
+    let options = parse_args(args("1 3-5 12")).unwrap();
+    assert_eq!(vec![1, 3, 4, 5, 12], options.days);
+    assert_eq!(DEFAULT_YEAR, options.year);
+    assert_eq!(None, options.part);
+    assert_eq!(1, options.repeat);
+}
+
+#[test]
+fn test_parse_all() {
+    let options = parse_args(args("all")).unwrap();
+    assert_eq!((1..=25).collect::<Vec<u8>>(), options.days);
+}
+
+#[test]
+fn test_parse_options() {
+    let options = parse_args(args("--part 2 -r 10 -q -e 7")).unwrap();
+    assert!(!options.record);
+    assert!(!options.parallel);
+    assert!(parse_args(args("all --parallel")).unwrap().parallel);
+    assert_eq!(Format::Human, options.format);
+    assert_eq!(Format::Csv, parse_args(args("1 --format csv")).unwrap().format);
+    assert_eq!(vec![7], options.days);
+    assert_eq!(Some(Part::Two), options.part);
+    assert_eq!(10, options.repeat);
+    assert!(options.quiet);
+    assert!(options.example);
+}
+
+#[test]
+fn test_parse_bench() {
+    let options = parse_args(args("bench 1-2 --warmup 0 --samples 5 --baseline b.txt")).unwrap();
+    assert_eq!(Command::Bench, options.command);
+    assert_eq!(vec![1, 2], options.days);
+    assert_eq!(0, options.warmup);
+    assert_eq!(5, options.samples);
+    assert_eq!(Some(PathBuf::from("b.txt")), options.baseline);
+    assert_eq!(Command::Run, parse_args(args("1")).unwrap().command);
+}
+
+#[test]
+fn test_parse_list() {
+    let options = parse_args(args("list")).unwrap();
+    assert_eq!(Command::List, options.command);
+    assert_eq!(25, options.days.len());
+    assert_eq!(vec![3, 4], parse_args(args("list 3-4")).unwrap().days);
+    assert_eq!(2022, parse_args(args("list --year 2022")).unwrap().year);
+    assert!(matches!(parse_args(args("list --part 1")), Err(CliError::Invalid(_))));
+}
+
+#[test]
+fn test_parse_submit() {
+    let options = parse_args(args("submit 5 2")).unwrap();
+    assert_eq!(Command::Submit, options.command);
+    assert_eq!(vec![5], options.days);
+    assert_eq!(Some(Part::Two), options.part);
+    assert!(matches!(parse_args(args("submit 5")), Err(CliError::Invalid(_))));
+    assert!(matches!(parse_args(args("submit 5 3")), Err(CliError::Invalid(_))));
+    assert!(matches!(parse_args(args("submit 5 1 2")), Err(CliError::Invalid(_))));
+    assert!(matches!(parse_args(args("submit 1-2 1")), Err(CliError::Invalid(_))));
+    assert!(matches!(parse_args(args("submit 5 1 --example")), Err(CliError::Invalid(_))));
+}
+
+#[test]
+fn test_parse_new() {
+    let options = parse_args(args("new 9 --year 2024")).unwrap();
+    assert_eq!(Command::New, options.command);
+    assert_eq!(vec![9], options.days);
+    assert_eq!(2024, options.year);
+    assert!(matches!(parse_args(args("new")), Err(CliError::Invalid(_))));
+    assert!(matches!(parse_args(args("new 1-2")), Err(CliError::Invalid(_))));
+    assert!(matches!(parse_args(args("new 3 --example")), Err(CliError::Invalid(_))));
+}
+
+#[test]
+fn test_parse_errors() {
+    assert!(matches!(parse_args(args("")), Err(CliError::Invalid(_))));
+    assert!(matches!(parse_args(args("26")), Err(CliError::Invalid(_))));
+    assert!(matches!(parse_args(args("5-3")), Err(CliError::Invalid(_))));
+    assert!(matches!(parse_args(args("1 --part 3")), Err(CliError::Invalid(_))));
+    assert!(matches!(parse_args(args("1 --year 2014")), Err(CliError::Invalid(_))));
+    assert!(matches!(parse_args(args("1 --repeat")), Err(CliError::Invalid(_))));
+    assert!(matches!(parse_args(args("1-2 --input x.txt")), Err(CliError::Invalid(_))));
+    assert!(matches!(parse_args(args("1 --verbose")), Err(CliError::Invalid(_))));
+    assert!(matches!(parse_args(args("1 --baseline b.txt")), Err(CliError::Invalid(_))));
+    assert!(matches!(parse_args(args("bench 1 --samples 0")), Err(CliError::Invalid(_))));
+    assert!(matches!(parse_args(args("1 --example --record")), Err(CliError::Invalid(_))));
+    assert!(matches!(parse_args(args("1 --format xml")), Err(CliError::Invalid(_))));
+    assert!(matches!(parse_args(args("bench 1 --parallel")), Err(CliError::Invalid(_))));
+    assert!(matches!(parse_args(args("bench 1 --repeat 5")), Err(CliError::Invalid(_))));
+    assert!(matches!(parse_args(args("bench 1 --quiet")), Err(CliError::Invalid(_))));
+    assert_eq!(Err(CliError::Help), parse_args(args("1 --help")));
+}
@@ -0,0 +1,37 @@
+use std::fmt::{Display, Formatter, Result};
+use std::num::ParseIntError;
+
+#[derive(Debug)]
+pub enum SolveError {
+    /// The input did not have the expected format.
+    Parse(String),
+    /// The input parsed, but no answer could be derived from it.
+    NoAnswer(String),
+}
+
+impl SolveError {
+    pub fn parse(msg: impl Into<String>) -> Self {
+        SolveError::Parse(msg.into())
+    }
+
+    pub fn no_answer(msg: impl Into<String>) -> Self {
+        SolveError::NoAnswer(msg.into())
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            SolveError::Parse(msg) => write!(f, "parse error: {}", msg),
+            SolveError::NoAnswer(msg) => write!(f, "no answer: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<ParseIntError> for SolveError {
+    fn from(err: ParseIntError) -> Self {
+        SolveError::Parse(err.to_string())
+    }
+}
@@ -0,0 +1,97 @@
+use super::answers;
+use super::input;
+use super::solver::Part;
+use super::Solution;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// A puzzle example, `<year>/examples/dayNN_K.txt`, with the answers the
+/// puzzle text gives for it in the sidecar `dayNN_K.toml`:
+///
+/// ```toml
+/// part1 = 35
+/// part2 = "46"
+/// ```
+///
+/// Either part may be left out, as examples often only apply to one part.
+pub struct Example {
+    pub input: String,
+    pub expected: Vec<(Part, Solution)>,
+}
+
+pub fn examples_dir(year: u16) -> PathBuf {
+    input::year_dir(year).join("examples")
+}
+
+/// Path of the `index`th example of a day, counting from 1.
+pub fn example_path(year: u16, day: u8, index: usize) -> PathBuf {
+    examples_dir(year).join(format!("day{:02}_{}.txt", day, index))
+}
+
+/// Loads every example of a day in order, stopping at the first missing index.
+pub fn load_examples(year: u16, day: u8) -> io::Result<Vec<Example>> {
+    let mut examples = Vec::new();
+    for index in 1.. {
+        let path = example_path(year, day, index);
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => break,
+            Err(err) => return Err(err),
+        };
+        let sidecar = fs::read_to_string(path.with_extension("toml"))?;
+        let expected = parse_sidecar(&sidecar).map_err(|line| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: invalid entry {:?}", path.with_extension("toml").display(), line),
+            )
+        })?;
+        examples.push(Example {
+            input,
+            expected,
+        });
+    }
+    Ok(examples)
+}
+
+/// The example to run when only `part` is selected: the first one whose
+/// sidecar has an answer for it. With no part selected it is the first example.
+pub fn select(examples: Vec<Example>, part: Option<Part>) -> Option<Example> {
+    examples
+        .into_iter()
+        .find(|example| part.is_none_or(|part| example.expected.iter().any(|(p, _)| *p == part)))
+}
+
+/// Reads the expected answers of a sidecar, failing with the offending line.
+fn parse_sidecar(s: &str) -> Result<Vec<(Part, Solution)>, &str> {
+    s.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| answers::parse_entry(line).ok_or(line))
+        .collect()
+}
+
+#[test]
+fn test_parse_sidecar() {
+    let expected = parse_sidecar("# from the puzzle text\npart1 = 35\n\npart2 = \"46\"\n").unwrap();
+    assert_eq!(vec![(Part::One, Solution::U8(35)), (Part::Two, Solution::U8(46))], expected);
+    assert_eq!(Err("part3 = 1"), parse_sidecar("part3 = 1"));
+}
+
+#[test]
+fn test_load_examples() {
+    let examples = load_examples(2023, 1).unwrap();
+    assert_eq!(2, examples.len());
+    assert_eq!(vec![(Part::One, Solution::U8(142))], examples[0].expected);
+    assert!(load_examples(2023, 25).unwrap().is_empty());
+}
+
+#[test]
+fn test_select() {
+    let first = select(load_examples(2023, 1).unwrap(), None).unwrap();
+    assert_eq!(vec![(Part::One, Solution::U8(142))], first.expected);
+    let second = select(load_examples(2023, 1).unwrap(), Some(Part::Two)).unwrap();
+    assert_eq!(vec![(Part::Two, Solution::U16(281))], second.expected);
+    assert!(select(load_examples(2023, 6).unwrap(), Some(Part::Two)).is_some_and(|e| e.expected.len() == 2));
+    assert!(select(Vec::new(), None).is_none());
+}
@@ -0,0 +1,271 @@
+use super::solver::Part;
+use super::submit::Submitter;
+use std::env;
+use std::fmt::{Display, Formatter, Result};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Site the puzzle inputs are downloaded from; `AOC_BASE_URL` overrides it.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Minimum time between two downloads, to go easy on the server.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(3);
+
+const USER_AGENT: &str = "advent_of_code_template (github.com/micheald13/aoc_2023)";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FetchError {
+    NoToken,
+    /// The server answered with an error status and body.
+    Status(u16, String),
+    Transport(String),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            FetchError::NoToken => write!(
+                f,
+                "no session token configured: set AOC_SESSION to the value of your session cookie"
+            ),
+            FetchError::Status(status, body) => write!(f, "server returned {}: {}", status, body),
+            FetchError::Transport(err) => write!(f, "request failed: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// Something that can download the puzzle input of a day. Closures work too,
+/// which is handy for tests.
+pub trait Fetcher: Sync {
+    fn fetch(&self, year: u16, day: u8) -> std::result::Result<String, FetchError>;
+}
+
+impl<F> Fetcher for F
+where
+    F: Fn(u16, u8) -> std::result::Result<String, FetchError> + Sync,
+{
+    fn fetch(&self, year: u16, day: u8) -> std::result::Result<String, FetchError> {
+        self(year, day)
+    }
+}
+
+/// Spaces calls to `wait` at least `interval` apart, across threads.
+pub struct RateLimiter {
+    interval: Duration,
+    last: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    pub fn new(interval: Duration) -> RateLimiter {
+        RateLimiter {
+            interval,
+            last: Mutex::new(None),
+        }
+    }
+
+    pub fn wait(&self) {
+        let mut last = self.last.lock().unwrap_or_else(|err| err.into_inner());
+        if let Some(previous) = *last {
+            let elapsed = previous.elapsed();
+            if elapsed < self.interval {
+                thread::sleep(self.interval - elapsed);
+            }
+        }
+        *last = Some(Instant::now());
+    }
+}
+
+/// Downloads inputs from `<base_url>/<year>/day/<day>/input` and posts answers
+/// to `<base_url>/<year>/day/<day>/answer`, authenticated with the session
+/// cookie of the site. Both kinds of request share one rate limit.
+pub struct HttpFetcher {
+    base_url: String,
+    token: Option<String>,
+    limiter: RateLimiter,
+}
+
+impl HttpFetcher {
+    pub fn new(base_url: &str, token: Option<String>, interval: Duration) -> HttpFetcher {
+        HttpFetcher {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            token,
+            limiter: RateLimiter::new(interval),
+        }
+    }
+
+    /// Configured from `AOC_BASE_URL` and `AOC_SESSION`.
+    pub fn from_env() -> HttpFetcher {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let token = env::var("AOC_SESSION")
+            .ok()
+            .map(|token| token.trim().to_string())
+            .filter(|token| !token.is_empty());
+        HttpFetcher::new(&base_url, token, DEFAULT_INTERVAL)
+    }
+
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    fn request(&self, method: &str, url: &str) -> std::result::Result<ureq::Request, FetchError> {
+        let token = self.token.as_ref().ok_or(FetchError::NoToken)?;
+        self.limiter.wait();
+        Ok(ureq::request(method, url)
+            .set("Cookie", &format!("session={}", token))
+            .set("User-Agent", USER_AGENT))
+    }
+}
+
+fn into_body(response: std::result::Result<ureq::Response, ureq::Error>) -> std::result::Result<String, FetchError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|err| FetchError::Transport(err.to_string())),
+        Err(ureq::Error::Status(status, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            Err(FetchError::Status(status, body.trim().to_string()))
+        }
+        Err(err) => Err(FetchError::Transport(err.to_string())),
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> std::result::Result<String, FetchError> {
+        into_body(self.request("GET", &self.url(year, day))?.call())
+    }
+}
+
+impl Submitter for HttpFetcher {
+    fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> std::result::Result<String, FetchError> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let level = part.to_string();
+        into_body(self.request("POST", &url)?.send_form(&[("level", &level), ("answer", answer)]))
+    }
+}
+
+/// A stand-in for the puzzle site in tests: serves the files under `root`
+/// over plain HTTP on a local port, whatever the method, answering `400` to
+/// requests without a session cookie like the real site does.
+#[cfg(test)]
+pub struct FileServer {
+    pub base_url: String,
+    pub requests: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    /// Body of the last request, e.g. a submitted form.
+    pub last_body: std::sync::Arc<Mutex<String>>,
+}
+
+#[cfg(test)]
+impl FileServer {
+    pub fn start(root: std::path::PathBuf) -> FileServer {
+        use std::io::{BufRead, BufReader, Read, Write};
+        use std::net::TcpListener;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+        let last_body = Arc::new(Mutex::new(String::new()));
+        let body_slot = Arc::clone(&last_body);
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut reader = BufReader::new(&stream);
+                let mut head = Vec::new();
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap_or(0) == 0 || line.trim().is_empty() {
+                        break;
+                    }
+                    head.push(line.trim().to_string());
+                }
+                let path = head.first().and_then(|l| l.split_whitespace().nth(1)).unwrap_or("/");
+                let has_cookie = head.iter().any(|l| l.to_lowercase().starts_with("cookie: session="));
+                let length = (head.iter())
+                    .find_map(|l| l.to_lowercase().strip_prefix("content-length:").map(|n| n.trim().to_string()))
+                    .and_then(|n| n.parse().ok())
+                    .unwrap_or(0);
+                let mut request_body = vec![0; length];
+                let _ = reader.read_exact(&mut request_body);
+                *body_slot.lock().unwrap() = String::from_utf8_lossy(&request_body).into_owned();
+                let file = root.join(path.trim_start_matches('/'));
+                let (status, body) = match std::fs::read_to_string(file) {
+                    _ if !has_cookie => ("400 Bad Request", "Please log in.".to_string()),
+                    Ok(body) => ("200 OK", body),
+                    Err(_) => ("404 Not Found", "Not found.".to_string()),
+                };
+                let _ = write!(
+                    &stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+            }
+        });
+        FileServer {
+            base_url,
+            requests,
+            last_body,
+        }
+    }
+
+    pub fn request_count(&self) -> usize {
+        self.requests.load(std::sync::atomic::Ordering::SeqCst)
+    }
+}
+
+/// A fresh, empty directory under the system temp dir for one test.
+#[cfg(test)]
+pub fn temp_dir(name: &str) -> std::path::PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn test_http_fetcher() {
+    let root = temp_dir("fetch-http");
+    std::fs::create_dir_all(root.join("2023/day/5")).unwrap();
+    std::fs::write(root.join("2023/day/5/input"), "seeds: 1 2\n").unwrap();
+    let server = FileServer::start(root);
+
+    let fetcher = HttpFetcher::new(&server.base_url, Some("abc".to_string()), Duration::ZERO);
+    assert_eq!(Ok("seeds: 1 2\n".to_string()), fetcher.fetch(2023, 5));
+    assert!(matches!(fetcher.fetch(2023, 6), Err(FetchError::Status(404, _))));
+    assert_eq!(2, server.request_count());
+}
+
+#[test]
+fn test_http_submit() {
+    let root = temp_dir("fetch-submit");
+    std::fs::create_dir_all(root.join("2023/day/5")).unwrap();
+    std::fs::write(root.join("2023/day/5/answer"), "<p>That's the right answer!</p>").unwrap();
+    let server = FileServer::start(root);
+
+    let client = HttpFetcher::new(&server.base_url, Some("abc".to_string()), Duration::ZERO);
+    let page = client.submit(2023, 5, Part::Two, "46").unwrap();
+    assert!(page.contains("right answer"));
+    assert_eq!("level=2&answer=46", *server.last_body.lock().unwrap());
+}
+
+#[test]
+fn test_missing_token() {
+    let fetcher = HttpFetcher::new("http://127.0.0.1:9", None, Duration::ZERO);
+    assert_eq!(Err(FetchError::NoToken), fetcher.fetch(2023, 1));
+    assert!(FetchError::NoToken.to_string().contains("AOC_SESSION"));
+}
+
+#[test]
+fn test_rate_limiter() {
+    let limiter = RateLimiter::new(Duration::from_millis(30));
+    let start = Instant::now();
+    for _ in 0..3 {
+        limiter.wait();
+    }
+    assert!(start.elapsed() >= Duration::from_millis(60));
+}
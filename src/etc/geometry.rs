@@ -0,0 +1,308 @@
+use super::grid::Grid;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point on a plane. On a grid `x` is the column and `y` the row, so `y`
+/// grows downwards and `North` is towards smaller `y`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(dead_code)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// `|a - b|`, also for unsigned types.
+fn abs_diff<T: Ord + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Point2<T> {
+        Point2 { x, y }
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> Point2<T> {
+    /// Steps needed to reach `other` moving only horizontally and vertically.
+    pub fn manhattan(self, other: Point2<T>) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Steps needed to reach `other` when diagonal moves are allowed too.
+    #[allow(dead_code)]
+    pub fn chebyshev(self, other: Point2<T>) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+}
+
+impl<T: Copy + TryInto<usize>> Point2<T> {
+    /// The `(row, column)` of the point, if neither coordinate is negative or
+    /// too large for a `usize`.
+    #[allow(dead_code)]
+    pub fn to_index(self) -> Option<(usize, usize)> {
+        Some((self.y.try_into().ok()?, self.x.try_into().ok()?))
+    }
+
+    /// Like `to_index`, but also `None` if the point lies outside `grid`.
+    #[allow(dead_code)]
+    pub fn in_grid<C>(self, grid: &Grid<C>) -> Option<(usize, usize)> {
+        self.to_index().filter(|&index| grid.contains(index))
+    }
+}
+
+impl<T: TryFrom<usize>> Point2<T> {
+    /// The point at `(row, column)`, if both fit in `T`.
+    #[allow(dead_code)]
+    pub fn from_index((row, col): (usize, usize)) -> Option<Point2<T>> {
+        Some(Point2::new(col.try_into().ok()?, row.try_into().ok()?))
+    }
+}
+
+impl Point2<usize> {
+    /// The neighbour in direction `dir`, unless that would leave the
+    /// non-negative quadrant.
+    #[allow(dead_code)]
+    pub fn checked_step(self, dir: impl Into<Direction8>) -> Option<Point2<usize>> {
+        let offset: Point2<isize> = dir.into().offset();
+        Some(Point2::new(
+            self.x.checked_add_signed(offset.x)?,
+            self.y.checked_add_signed(offset.y)?,
+        ))
+    }
+}
+
+#[allow(dead_code)]
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Point3<T> {
+        Point3 { x, y, z }
+    }
+}
+
+#[allow(dead_code)]
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> Point3<T> {
+    pub fn manhattan(self, other: Point3<T>) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+
+    pub fn chebyshev(self, other: Point3<T>) -> T {
+        abs_diff(self.x, other.x)
+            .max(abs_diff(self.y, other.y))
+            .max(abs_diff(self.z, other.z))
+    }
+}
+
+/// Component-wise operators, plus scaling by a `T`.
+macro_rules! point_ops {
+    ($point:ident { $($field:ident),+ }) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = $point<T>;
+
+            fn add(self, other: $point<T>) -> $point<T> {
+                $point { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = $point<T>;
+
+            fn sub(self, other: $point<T>) -> $point<T> {
+                $point { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $point<T> {
+            type Output = $point<T>;
+
+            fn mul(self, factor: T) -> $point<T> {
+                $point { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = $point<T>;
+
+            fn neg(self) -> $point<T> {
+                $point { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: $point<T>) {
+                $(self.$field += other.$field;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: $point<T>) {
+                $(self.$field -= other.$field;)+
+            }
+        }
+    };
+}
+
+point_ops!(Point2 { x, y });
+point_ops!(Point3 { x, y, z });
+
+/// One of the four directions on a grid, clockwise from `North`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(dead_code)]
+pub enum Direction4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// One of the eight directions on a grid, clockwise from `North`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(dead_code)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+#[allow(dead_code)]
+impl Direction4 {
+    pub const ALL: [Direction4; 4] = [Direction4::North, Direction4::East, Direction4::South, Direction4::West];
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Direction4 {
+        Direction4::ALL[(self as usize + 1) % 4]
+    }
+
+    /// A quarter turn counterclockwise.
+    pub fn turn_left(self) -> Direction4 {
+        Direction4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Direction4 {
+        Direction4::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The step of one cell in this direction, e.g. `(0, -1)` for `North`.
+    pub fn offset<T: From<i8>>(self) -> Point2<T> {
+        Direction8::from(self).offset()
+    }
+}
+
+#[allow(dead_code)]
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// An eighth of a turn clockwise.
+    pub fn turn_right(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// An eighth of a turn counterclockwise.
+    pub fn turn_left(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    /// The step of one cell in this direction, e.g. `(1, -1)` for `NorthEast`.
+    pub fn offset<T: From<i8>>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Direction8::North => (0, -1),
+            Direction8::NorthEast => (1, -1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, -1),
+        };
+        Point2::new(T::from(x), T::from(y))
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(dir: Direction4) -> Direction8 {
+        Direction8::ALL[dir as usize * 2]
+    }
+}
+
+#[test]
+fn test_point_arithmetic() {
+    let a = Point2::new(3, -2);
+    let b = Point2::new(1, 5);
+    assert_eq!(Point2::new(4, 3), a + b);
+    assert_eq!(Point2::new(2, -7), a - b);
+    assert_eq!(Point2::new(6, -4), a * 2);
+    assert_eq!(Point2::new(-3, 2), -a);
+    let mut c = a;
+    c += b;
+    c -= Point2::new(1, 1);
+    assert_eq!(Point2::new(3, 2), c);
+    assert_eq!(Point3::new(2, 4, 6), Point3::new(1, 2, 3) + Point3::new(1, 2, 3));
+}
+
+#[test]
+fn test_distances() {
+    let a = Point2::new(1usize, 6);
+    let b = Point2::new(5usize, 11);
+    assert_eq!(9, a.manhattan(b));
+    assert_eq!(9, b.manhattan(a));
+    assert_eq!(5, a.chebyshev(b));
+    let c = Point3::new(0i64, -3, 2);
+    assert_eq!(10, c.manhattan(Point3::new(4, 0, -1)));
+    assert_eq!(4, c.chebyshev(Point3::new(4, 0, -1)));
+}
+
+#[test]
+fn test_turning() {
+    assert_eq!(Direction4::East, Direction4::North.turn_right());
+    assert_eq!(Direction4::West, Direction4::North.turn_left());
+    assert_eq!(Direction4::North, Direction4::South.reverse());
+    assert_eq!(Direction8::NorthWest, Direction8::North.turn_left());
+    assert_eq!(Direction8::SouthWest, Direction8::NorthEast.reverse());
+    for dir in Direction8::ALL {
+        assert_eq!(dir, dir.turn_right().turn_left());
+        assert_eq!(-dir.offset::<i32>(), dir.reverse().offset());
+    }
+    assert_eq!(Point2::new(-1i64, 0), Direction4::West.offset());
+}
+
+#[test]
+fn test_grid_indices() {
+    assert_eq!(Some((2, 1)), Point2::new(1i32, 2).to_index());
+    assert_eq!(None, Point2::new(-1i32, 2).to_index());
+    assert_eq!(Some(Point2::new(1i8, 2)), Point2::from_index((2, 1)));
+    assert_eq!(None, Point2::<i8>::from_index((200, 1)));
+
+    let grid = Grid::new(3, 2, '.');
+    assert_eq!(Some((1, 2)), Point2::new(2i64, 1).in_grid(&grid));
+    assert_eq!(None, Point2::new(3i64, 1).in_grid(&grid));
+
+    let origin = Point2::new(0usize, 0);
+    assert_eq!(None, origin.checked_step(Direction4::North));
+    assert_eq!(Some(Point2::new(1, 1)), origin.checked_step(Direction8::SouthEast));
+}
@@ -0,0 +1,314 @@
+use super::SolveError;
+use std::fmt::{Display, Formatter, Result};
+use std::ops::{Index, IndexMut};
+
+/// A rectangular map of cells, addressed by `(row, column)` from the top left
+/// and stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+#[allow(dead_code)]
+const OFFSETS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+const OFFSETS8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+impl<T> Grid<T> {
+    /// Parses one row per line, converting every character with `cell`.
+    /// Fails on a character `cell` rejects or on rows of different lengths.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> std::result::Result<Grid<T>, SolveError> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+        for (row, line) in input.lines().enumerate() {
+            let start = cells.len();
+            for (col, c) in line.chars().enumerate() {
+                let value = cell(c).ok_or_else(|| {
+                    SolveError::parse(format!("unexpected {:?} at row {}, column {}", c, row + 1, col + 1))
+                })?;
+                cells.push(value);
+            }
+            let len = cells.len() - start;
+            match width {
+                Some(width) if width != len => {
+                    return Err(SolveError::parse(format!(
+                        "row {} has {} cells, expected {}",
+                        row + 1,
+                        len,
+                        width
+                    )))
+                }
+                _ => width = Some(len),
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    /// Builds a grid from its rows, which must all have the same length.
+    #[allow(dead_code)]
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        let cells = rows.into_iter().flatten().collect();
+        Some(Grid { width, height, cells })
+    }
+
+    #[allow(dead_code)]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[allow(dead_code)]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): (usize, usize)) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// The cell `offset` away from `pos`, if that is still on the grid.
+    #[allow(dead_code)]
+    pub fn offset(&self, (row, col): (usize, usize), (dr, dc): (isize, isize)) -> Option<(usize, usize)> {
+        let pos = (row.checked_add_signed(dr)?, col.checked_add_signed(dc)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The positions above, right of, below and left of `pos` that are on the
+    /// grid, in that order.
+    #[allow(dead_code)]
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS4.iter().filter_map(move |&offset| self.offset(pos, offset))
+    }
+
+    /// Like `neighbours4`, including the diagonals, clockwise from above.
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS8.iter().filter_map(move |&offset| self.offset(pos, offset))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Positions of the cells matching `predicate`, row by row.
+    pub fn find_all<'a>(&'a self, mut predicate: impl FnMut(&T) -> bool + 'a) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.iter().filter(move |(_, cell)| predicate(cell)).map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |row| self.row(row))
+    }
+
+    #[allow(dead_code)]
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {} out of range for width {}", col, self.width);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    #[allow(dead_code)]
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    #[allow(dead_code)]
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Reverses every row.
+    #[allow(dead_code)]
+    pub fn flip_horizontal(&mut self) {
+        if self.width > 0 {
+            self.cells.chunks_mut(self.width).for_each(<[T]>::reverse);
+        }
+    }
+
+    /// Reverses the order of the rows.
+    #[allow(dead_code)]
+    pub fn flip_vertical(&mut self) {
+        for row in 0..self.height / 2 {
+            let other = self.height - 1 - row;
+            let (top, bottom) = self.cells.split_at_mut(other * self.width);
+            top[row * self.width..(row + 1) * self.width].swap_with_slice(&mut bottom[..self.width]);
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    #[allow(dead_code)]
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, so rows become columns.
+    #[allow(dead_code)]
+    pub fn transpose(&self) -> Grid<T> {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    #[allow(dead_code)]
+    pub fn rotate_cw(&self) -> Grid<T> {
+        let mut rotated = self.transpose();
+        rotated.flip_horizontal();
+        rotated
+    }
+
+    /// Rotates the grid a quarter turn counterclockwise.
+    #[allow(dead_code)]
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        let mut rotated = self.transpose();
+        rotated.flip_vertical();
+        rotated
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} out of range for a {}x{} grid", pos, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} out of range for a {}x{} grid", pos, width, height))
+    }
+}
+
+/// One line per row with the cells side by side, so a `Grid<char>` prints
+/// back as the text it was parsed from.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+fn sample() -> Grid<char> {
+    Grid::parse("abc\ndef", Some).unwrap()
+}
+
+#[test]
+fn test_parse() {
+    let grid = sample();
+    assert_eq!((3, 2), (grid.width(), grid.height()));
+    assert_eq!('f', grid[(1, 2)]);
+    assert_eq!(None, grid.get((2, 0)));
+    assert_eq!(None, grid.get((0, 3)));
+    assert!(Grid::parse("ab\nc", Some).is_err());
+    assert!(Grid::parse("#.x", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+    .is_err());
+    assert_eq!(0, Grid::parse("", Some).unwrap().height());
+}
+
+#[test]
+fn test_neighbours() {
+    let grid = Grid::new(3, 3, 0u8);
+    let corner: Vec<_> = grid.neighbours4((0, 0)).collect();
+    assert_eq!(vec![(0, 1), (1, 0)], corner);
+    assert_eq!(4, grid.neighbours4((1, 1)).count());
+    assert_eq!(3, grid.neighbours8((2, 2)).count());
+    let centre: Vec<_> = grid.neighbours8((1, 1)).collect();
+    assert_eq!((0, 1), centre[0]);
+    assert_eq!((0, 0), centre[7]);
+}
+
+#[test]
+fn test_rows_and_columns() {
+    let grid = sample();
+    let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+    assert_eq!(vec!["abc", "def"], rows);
+    let columns: Vec<String> = grid.columns().map(|col| col.collect()).collect();
+    assert_eq!(vec!["ad", "be", "cf"], columns);
+    assert_eq!(vec![(1, 0)], grid.find_all(|&c| c == 'd').collect::<Vec<_>>());
+}
+
+#[test]
+fn test_transpose_and_rotate() {
+    let grid = sample();
+    assert_eq!("ad\nbe\ncf", grid.transpose().to_string());
+    assert_eq!("da\neb\nfc", grid.rotate_cw().to_string());
+    assert_eq!("cf\nbe\nad", grid.rotate_ccw().to_string());
+    assert_eq!(grid, grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw());
+    assert_eq!(grid, grid.transpose().transpose());
+}
+
+#[test]
+fn test_display() {
+    let input = "#..\n.#.";
+    assert_eq!(input, Grid::parse(input, Some).unwrap().to_string());
+    let grid = Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    assert_eq!("12\n34", grid.to_string());
+    assert_eq!(None, Grid::from_rows(vec![vec![1, 2], vec![3]]));
+}
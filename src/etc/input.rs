@@ -0,0 +1,128 @@
+use super::fetch::{FetchError, Fetcher};
+use std::env;
+use std::fmt::{Display, Formatter, Result};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Directory the puzzle inputs are read from, relative to the working directory,
+/// with one subdirectory per year. Can be overridden with the `AOC_INPUT_DIR`
+/// environment variable.
+pub const INPUT_DIR: &str = "input";
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing(PathBuf),
+    Io(PathBuf, io::Error),
+    /// Not in the cache, and downloading it failed.
+    Fetch(PathBuf, FetchError),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            InputError::Missing(path) => write!(f, "input file not found: {}", path.display()),
+            InputError::Io(path, err) => write!(f, "could not read {}: {}", path.display(), err),
+            InputError::Fetch(path, err) => {
+                write!(f, "input file not found: {}, and downloading it failed: {}", path.display(), err)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+pub fn input_dir() -> PathBuf {
+    env::var_os("AOC_INPUT_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(INPUT_DIR))
+}
+
+pub fn year_dir(year: u16) -> PathBuf {
+    input_dir().join(year.to_string())
+}
+
+pub fn input_path(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(year.to_string()).join(format!("input{:02}.txt", day))
+}
+
+pub fn read_input(path: &Path) -> std::result::Result<String, InputError> {
+    fs::read_to_string(path).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => InputError::Missing(path.to_path_buf()),
+        _ => InputError::Io(path.to_path_buf(), err),
+    })
+}
+
+/// Finds puzzle inputs: the cache directory first, otherwise the fetcher,
+/// whose download is saved to the cache for the next run.
+pub struct InputProvider<F> {
+    cache_dir: PathBuf,
+    fetcher: F,
+}
+
+impl<F: Fetcher> InputProvider<F> {
+    pub fn new(cache_dir: PathBuf, fetcher: F) -> InputProvider<F> {
+        InputProvider { cache_dir, fetcher }
+    }
+
+    pub fn fetcher(&self) -> &F {
+        &self.fetcher
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> std::result::Result<String, InputError> {
+        let path = input_path(&self.cache_dir, year, day);
+        match read_input(&path) {
+            Err(InputError::Missing(_)) => {}
+            cached => return cached,
+        }
+
+        let input = (self.fetcher)
+            .fetch(year, day)
+            .map_err(|err| InputError::Fetch(path.clone(), err))?;
+        let write = |path: &Path| {
+            fs::create_dir_all(path.parent().unwrap_or(Path::new(".")))?;
+            fs::write(path, &input)
+        };
+        write(&path).map_err(|err| InputError::Io(path.clone(), err))?;
+        Ok(input)
+    }
+}
+
+#[test]
+fn test_input_path() {
+    assert_eq!(Path::new("input/2023/input05.txt"), input_path(Path::new("input"), 2023, 5));
+}
+
+#[test]
+fn test_missing_input() {
+    let path = Path::new("input/does_not_exist.txt");
+    assert!(matches!(read_input(path), Err(InputError::Missing(_))));
+}
+
+#[test]
+fn test_provider_caches_downloads() {
+    use super::fetch::{temp_dir, FileServer, HttpFetcher};
+    use std::time::Duration;
+
+    let site = temp_dir("provider-site");
+    fs::create_dir_all(site.join("2023/day/1")).unwrap();
+    fs::write(site.join("2023/day/1/input"), "1abc2\n").unwrap();
+    let server = FileServer::start(site);
+
+    let cache = temp_dir("provider-cache");
+    let fetcher = HttpFetcher::new(&server.base_url, Some("abc".to_string()), Duration::ZERO);
+    let provider = InputProvider::new(cache.clone(), fetcher);
+    assert_eq!("1abc2\n", provider.get(2023, 1).unwrap());
+    assert_eq!("1abc2\n", provider.get(2023, 1).unwrap());
+    assert_eq!(1, server.request_count());
+    assert!(cache.join("2023/input01.txt").exists());
+    assert!(matches!(provider.get(2023, 2), Err(InputError::Fetch(_, _))));
+}
+
+#[test]
+fn test_provider_without_token() {
+    let fetcher = |_, _| Err(FetchError::NoToken);
+    let provider = InputProvider::new(super::fetch::temp_dir("provider-no-token"), fetcher);
+    let err = provider.get(2023, 1).unwrap_err();
+    assert!(err.to_string().contains("AOC_SESSION"));
+}
@@ -1,3 +1,19 @@
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod error;
+pub mod examples;
+pub mod fetch;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod report;
+pub mod search;
 pub mod solution;
+pub mod solver;
+pub mod submit;
+pub mod template;
 
-pub use solution::Solution;
\ No newline at end of file
+pub use error::SolveError;
+pub use solution::Solution;
+pub use solver::{Part, Solver};
@@ -0,0 +1,220 @@
+use super::answers::Verdict;
+use super::solver::Part;
+use super::Solution;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Human,
+    Json,
+    Csv,
+    Markdown,
+}
+
+/// The outcome of one part of one day, as written by the machine-readable
+/// formats. With the `serde` feature it can also be stored as run history;
+/// the answer keeps its `Solution` variant, so a later run can be diffed
+/// against it exactly.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RunReport {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: Result<Solution, String>,
+    /// Time spent parsing the day's input, shared by both parts.
+    pub parse: Duration,
+    pub elapsed: Duration,
+    pub verdict: Verdict,
+}
+
+impl RunReport {
+    pub fn status(&self) -> &'static str {
+        match (&self.answer, &self.verdict) {
+            (Err(_), _) => "error",
+            (Ok(_), Verdict::Correct) => "correct",
+            (Ok(_), Verdict::Wrong(_)) => "wrong",
+            (Ok(_), Verdict::Unknown) => "unknown",
+        }
+    }
+
+    fn elapsed_ms(&self) -> f64 {
+        self.elapsed.as_nanos() as f64 / 1_000_000.0
+    }
+
+    fn answer_text(&self) -> String {
+        match &self.answer {
+            Ok(answer) => answer.to_string(),
+            Err(err) => err.clone(),
+        }
+    }
+
+    fn type_name(&self) -> &'static str {
+        self.answer.as_ref().map_or("", |answer| answer.type_name())
+    }
+}
+
+pub fn render(format: Format, reports: &[RunReport]) -> String {
+    match format {
+        Format::Human => String::new(),
+        Format::Json => render_json(reports),
+        Format::Csv => render_csv(reports),
+        Format::Markdown => render_markdown(reports),
+    }
+}
+
+fn render_json(reports: &[RunReport]) -> String {
+    let rows: Vec<String> = reports
+        .iter()
+        .map(|r| {
+            let (answer, error) = match &r.answer {
+                Ok(answer) => (json_string(&answer.to_string()), "null".to_string()),
+                Err(err) => ("null".to_string(), json_string(err)),
+            };
+            format!(
+                "  {{\"year\": {}, \"day\": {}, \"part\": {}, \"answer\": {}, \"type\": {}, \"elapsed_ms\": {:.4}, \"status\": \"{}\", \"error\": {}}}",
+                r.year,
+                r.day,
+                r.part,
+                answer,
+                json_string(r.type_name()),
+                r.elapsed_ms(),
+                r.status(),
+                error
+            )
+        })
+        .collect();
+    if rows.is_empty() {
+        return "[]\n".to_string();
+    }
+    format!("[\n{}\n]\n", rows.join(",\n"))
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn render_csv(reports: &[RunReport]) -> String {
+    let mut out = String::from("year,day,part,answer,type,elapsed_ms,status\n");
+    for r in reports {
+        out.push_str(&format!(
+            "{},{},{},{},{},{:.4},{}\n",
+            r.year,
+            r.day,
+            r.part,
+            csv_field(&r.answer_text()),
+            r.type_name(),
+            r.elapsed_ms(),
+            r.status()
+        ));
+    }
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn render_markdown(reports: &[RunReport]) -> String {
+    let mut out = String::from("| Year | Day | Part | Answer | Type | Time (ms) | Status |\n");
+    out.push_str("|-----:|----:|-----:|--------|------|----------:|:------:|\n");
+    for r in reports {
+        let status = match (&r.answer, &r.verdict) {
+            (Err(_), _) => "error".to_string(),
+            (Ok(_), verdict) => verdict.to_string(),
+        };
+        out.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {:.4} | {} |\n",
+            r.year,
+            r.day,
+            r.part,
+            r.answer_text().replace('|', "\\|").replace('\n', "<br>"),
+            r.type_name(),
+            r.elapsed_ms(),
+            status
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+fn sample_reports() -> Vec<RunReport> {
+    vec![
+        RunReport {
+            year: 2023,
+            day: 1,
+            part: Part::One,
+            answer: Ok(Solution::from(142u64)),
+            parse: Duration::from_micros(20),
+            elapsed: Duration::from_micros(1500),
+            verdict: Verdict::Correct,
+        },
+        RunReport {
+            year: 2023,
+            day: 1,
+            part: Part::Two,
+            answer: Err("no answer: \"x\", y".to_string()),
+            parse: Duration::from_micros(20),
+            elapsed: Duration::ZERO,
+            verdict: Verdict::Unknown,
+        },
+    ]
+}
+
+#[test]
+fn test_render_json() {
+    let expected = "[
+  {\"year\": 2023, \"day\": 1, \"part\": 1, \"answer\": \"142\", \"type\": \"u64\", \"elapsed_ms\": 1.5000, \"status\": \"correct\", \"error\": null},
+  {\"year\": 2023, \"day\": 1, \"part\": 2, \"answer\": null, \"type\": \"\", \"elapsed_ms\": 0.0000, \"status\": \"error\", \"error\": \"no answer: \\\"x\\\", y\"}
+]
+";
+    assert_eq!(expected, render(Format::Json, &sample_reports()));
+    assert_eq!("[]\n", render(Format::Json, &[]));
+}
+
+#[test]
+fn test_render_csv() {
+    let expected = "year,day,part,answer,type,elapsed_ms,status
+2023,1,1,142,u64,1.5000,correct
+2023,1,2,\"no answer: \"\"x\"\", y\",,0.0000,error
+";
+    assert_eq!(expected, render(Format::Csv, &sample_reports()));
+}
+
+#[test]
+fn test_render_markdown() {
+    let rendered = render(Format::Markdown, &sample_reports());
+    let lines: Vec<&str> = rendered.lines().collect();
+    assert_eq!(4, lines.len());
+    assert_eq!("| 2023 | 1 | 1 | 142 | u64 | 1.5000 | ✔ |", lines[2]);
+    assert_eq!("| 2023 | 1 | 2 | no answer: \"x\", y |  | 0.0000 | error |", lines[3]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_report_round_trip() {
+    let reports = sample_reports();
+    let json = serde_json::to_string(&reports).unwrap();
+    assert!(json.contains("{\"U64\":142}"));
+    assert_eq!(reports, serde_json::from_str::<Vec<RunReport>>(&json).unwrap());
+}
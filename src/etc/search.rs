@@ -0,0 +1,234 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// What a search found: the shortest distance from the start to the nodes it
+/// settled, the predecessor of each on one shortest path, the goal if it was
+/// reached and how many nodes were expanded on the way. `bfs` settles a node
+/// as soon as it is discovered, `dijkstra` and `astar` only once they expand
+/// it, so nodes still queued when the goal was found have no distance.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    distances: HashMap<N, C>,
+    predecessors: HashMap<N, N>,
+    pub goal: Option<N>,
+    pub visited: usize,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Search<N, C> {
+    fn new() -> Search<N, C> {
+        Search {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goal: None,
+            visited: 0,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.distances
+    }
+
+    #[allow(dead_code)]
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    /// Distance to the goal, if it was reached.
+    #[allow(dead_code)]
+    pub fn goal_distance(&self) -> Option<C> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    /// The nodes on a shortest path from the start to `node`, both included.
+    #[allow(dead_code)]
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors.get(path.last()?) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search from `start`, counting every edge as one step. Stops
+/// at the first node `is_goal` accepts; `|_| false` explores everything
+/// reachable.
+#[allow(dead_code)]
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    search.distances.insert(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        search.visited += 1;
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        let next = search.distances[&node] + 1;
+        for neighbour in neighbours(&node) {
+            if !search.distances.contains_key(&neighbour) {
+                search.distances.insert(neighbour.clone(), next);
+                search.predecessors.insert(neighbour.clone(), node.clone());
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's algorithm from `start` over edges with non-negative costs,
+/// given as `(neighbour, cost)` pairs. Stops at the first node `is_goal`
+/// accepts.
+#[allow(dead_code)]
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search: Dijkstra's algorithm guided by `heuristic`, an estimate of the
+/// remaining cost to the goal. The distance found to the goal is the shortest
+/// one as long as the heuristic never overestimates it and is consistent.
+#[allow(dead_code)]
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new();
+    // The heap holds indices into `nodes`, so `N` needs no ordering, and
+    // `parents` the index each was reached from. Ties in the estimate go to
+    // the node furthest from the start, which is closest to the goal.
+    let mut tentative = HashMap::from([(start.clone(), C::default())]);
+    let mut nodes = vec![start];
+    let mut parents: Vec<Option<usize>> = vec![None];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&nodes[0]), Reverse(C::default()), 0))]);
+    while let Some(Reverse((_, Reverse(cost), index))) = heap.pop() {
+        let node = nodes[index].clone();
+        if search.distances.contains_key(&node) || tentative[&node] < cost {
+            // Expanded already, or a shorter way to it is queued.
+            continue;
+        }
+        search.distances.insert(node.clone(), cost);
+        if let Some(parent) = parents[index] {
+            search.predecessors.insert(node.clone(), nodes[parent].clone());
+        }
+        search.visited += 1;
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        for (neighbour, step) in neighbours(&node) {
+            let next = cost + step;
+            if tentative.get(&neighbour).is_some_and(|&known| known <= next) {
+                continue;
+            }
+            tentative.insert(neighbour.clone(), next);
+            heap.push(Reverse((next + heuristic(&neighbour), Reverse(next), nodes.len())));
+            nodes.push(neighbour);
+            parents.push(Some(index));
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+const GRAPH: &[(char, &[(char, u32)])] = &[
+    ('a', &[('b', 7), ('c', 9), ('f', 14)]),
+    ('b', &[('a', 7), ('c', 10), ('d', 15)]),
+    ('c', &[('a', 9), ('b', 10), ('d', 11), ('f', 2)]),
+    ('d', &[('b', 15), ('c', 11), ('e', 6)]),
+    ('e', &[('d', 6), ('f', 9)]),
+    ('f', &[('a', 14), ('c', 2), ('e', 9)]),
+    ('g', &[]),
+];
+
+#[cfg(test)]
+fn edges(node: &char) -> Vec<(char, u32)> {
+    GRAPH.iter().find(|(n, _)| n == node).unwrap().1.to_vec()
+}
+
+#[test]
+fn test_bfs() {
+    let search = bfs('a', |n| edges(n).into_iter().map(|(next, _)| next), |_| false);
+    assert_eq!(Some(2), search.distance(&'e'));
+    assert_eq!(Some(0), search.distance(&'a'));
+    assert_eq!(None, search.distance(&'g'));
+    assert_eq!(Some(vec!['a', 'f', 'e']), search.path(&'e'));
+    assert_eq!(6, search.visited);
+    assert_eq!(6, search.distances().len());
+
+    let search = bfs('a', |n| edges(n).into_iter().map(|(next, _)| next), |&n| n == 'b');
+    assert_eq!(Some('b'), search.goal);
+    assert_eq!(Some(1), search.goal_distance());
+    assert_eq!(2, search.visited);
+}
+
+#[test]
+fn test_dijkstra() {
+    let search = dijkstra('a', edges, |&n| n == 'e');
+    assert_eq!(Some(20), search.goal_distance());
+    assert_eq!(Some(vec!['a', 'c', 'f', 'e']), search.path(&'e'));
+    assert_eq!(Some(11), search.distance(&'f'));
+
+    // `f` is queued at 14, not yet lowered to 11 through `c`, when `c` is
+    // reached, so it has no distance yet.
+    let search = dijkstra('a', edges, |&n| n == 'c');
+    assert_eq!(Some(9), search.goal_distance());
+    assert_eq!(3, search.distances().len());
+    assert_eq!(None, search.distance(&'f'));
+    assert_eq!(None, search.path(&'f'));
+
+    let search = dijkstra('a', edges, |&n| n == 'g');
+    assert_eq!(None, search.goal);
+    assert_eq!(None, search.path(&'g'));
+    assert_eq!(Some(20), search.distance(&'d'));
+    assert_eq!(6, search.visited);
+}
+
+#[test]
+fn test_astar() {
+    // An open 20x20 grid: A* heads straight for the corner, Dijkstra floods.
+    let size = 20i32;
+    let grid_edges = |&(x, y): &(i32, i32)| {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|&(x, y)| (0..size).contains(&x) && (0..size).contains(&y))
+            .map(|pos| (pos, 1))
+    };
+    let goal = (size - 1, size - 1);
+    let manhattan = |&(x, y): &(i32, i32)| (goal.0 - x) + (goal.1 - y);
+
+    let guided = astar((0, 0), grid_edges, manhattan, |&pos| pos == goal);
+    let flooded = dijkstra((0, 0), grid_edges, |&pos| pos == goal);
+    assert_eq!(Some(38), guided.goal_distance());
+    assert_eq!(Some(38), flooded.goal_distance());
+    assert_eq!(39, guided.path(&goal).unwrap().len());
+    assert!(guided.visited < flooded.visited / 4);
+}
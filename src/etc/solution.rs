@@ -1,7 +1,16 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter, Result};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 use Solution::*;
 
-#[derive(Clone)]
+/// An answer of any supported type. Numbers compare by value regardless of
+/// their width, so `U64(5) == Usize(5)` and `F64(5.0) == I32(5)`; values of
+/// different kinds (say a number and a string) are never equal and unordered.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Solution {
     I8(i8),
     I16(i16),
@@ -15,7 +24,13 @@ pub enum Solution {
     U64(u64),
     U128(u128),
     Usize(usize),
+    F64(f64),
+    Bool(bool),
     Str(String),
+    /// Displayed as its elements separated by commas.
+    List(Vec<Solution>),
+    /// A 2D character grid, displayed one row per line.
+    Grid(Vec<Vec<char>>),
 }
 
 impl Display for Solution {
@@ -33,13 +48,229 @@ impl Display for Solution {
             U64(x) => x.fmt(f),
             U128(x) => x.fmt(f),
             Usize(x) => x.fmt(f),
+            F64(x) => x.fmt(f),
+            Bool(x) => x.fmt(f),
             Str(x) => x.fmt(f),
+            List(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    item.fmt(f)?;
+                }
+                Ok(())
+            }
+            Grid(rows) => {
+                for (i, row) in rows.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "{}", row.iter().collect::<String>())?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Solution {
+    /// Name of the variant's underlying type, e.g. `"u64"` or `"str"`.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            I8(_) => "i8",
+            I16(_) => "i16",
+            I32(_) => "i32",
+            I64(_) => "i64",
+            I128(_) => "i128",
+            Isize(_) => "isize",
+            U8(_) => "u8",
+            U16(_) => "u16",
+            U32(_) => "u32",
+            U64(_) => "u64",
+            U128(_) => "u128",
+            Usize(_) => "usize",
+            F64(_) => "f64",
+            Bool(_) => "bool",
+            Str(_) => "str",
+            List(_) => "list",
+            Grid(_) => "grid",
+        }
+    }
+}
+
+/// 2^128, the first float above every `Int`.
+const INT_LIMIT: f64 = 340282366920938463463374607431768211456.0;
+
+/// An integer of any width, with negative values kept apart so that every
+/// variant fits without loss. The derived order puts `Neg` below `NonNeg`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Int {
+    Neg(i128),
+    NonNeg(u128),
+}
+
+impl Int {
+    fn signed(x: i128) -> Int {
+        if x < 0 {
+            Int::Neg(x)
+        } else {
+            Int::NonNeg(x as u128)
+        }
+    }
+
+    /// The integer equal to `x`, if `x` is a whole number in range.
+    fn from_f64(x: f64) -> Option<Int> {
+        if !x.is_finite() || x.fract() != 0.0 || x.abs() >= INT_LIMIT {
+            return None;
+        }
+        if x < 0.0 {
+            (x >= i128::MIN as f64).then_some(Int::Neg(x as i128))
+        } else {
+            Some(Int::NonNeg(x as u128))
+        }
+    }
+
+    fn as_f64(self) -> f64 {
+        match self {
+            Int::Neg(x) => x as f64,
+            Int::NonNeg(x) => x as f64,
+        }
+    }
+}
+
+/// The numeric value of a solution. Whole floats are normalised to `Int`, so
+/// equal numbers always have the same representation.
+#[derive(Clone, Copy)]
+enum Number {
+    Int(Int),
+    Float(f64),
+}
+
+impl Number {
+    fn compare(self, other: Number) -> Ordering {
+        match (self, other) {
+            (Number::Int(a), Number::Int(b)) => a.cmp(&b),
+            (Number::Float(a), Number::Float(b)) => canonical(a).total_cmp(&canonical(b)),
+            (Number::Int(a), Number::Float(b)) => compare_int_float(a, b),
+            (Number::Float(a), Number::Int(b)) => compare_int_float(b, a).reverse(),
+        }
+    }
+}
+
+/// Collapses every NaN into one, so NaN answers equal each other.
+fn canonical(x: f64) -> f64 {
+    if x.is_nan() {
+        f64::NAN
+    } else {
+        x
+    }
+}
+
+/// `b` is never a whole number in range here, so the result is never `Equal`:
+/// NaN sorts above everything, as in `f64::total_cmp`.
+fn compare_int_float(a: Int, b: f64) -> Ordering {
+    if b.is_nan() || b >= INT_LIMIT {
+        Ordering::Less
+    } else if b < i128::MIN as f64 {
+        Ordering::Greater
+    } else {
+        a.as_f64().total_cmp(&b)
+    }
+}
+
+impl Solution {
+    fn number(&self) -> Option<Number> {
+        let int = match *self {
+            I8(x) => Int::signed(x.into()),
+            I16(x) => Int::signed(x.into()),
+            I32(x) => Int::signed(x.into()),
+            I64(x) => Int::signed(x.into()),
+            I128(x) => Int::signed(x),
+            Isize(x) => Int::signed(x as i128),
+            U8(x) => Int::NonNeg(x.into()),
+            U16(x) => Int::NonNeg(x.into()),
+            U32(x) => Int::NonNeg(x.into()),
+            U64(x) => Int::NonNeg(x.into()),
+            U128(x) => Int::NonNeg(x),
+            Usize(x) => Int::NonNeg(x as u128),
+            F64(x) => return Some(Int::from_f64(x).map_or(Number::Float(x), Number::Int)),
+            _ => return None,
+        };
+        Some(Number::Int(int))
+    }
+}
+
+impl PartialEq for Solution {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl Eq for Solution {}
+
+impl PartialOrd for Solution {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if let (Some(a), Some(b)) = (self.number(), other.number()) {
+            return Some(a.compare(b));
+        }
+        match (self, other) {
+            (Bool(a), Bool(b)) => a.partial_cmp(b),
+            (Str(a), Str(b)) => a.partial_cmp(b),
+            (List(a), List(b)) => a.partial_cmp(b),
+            (Grid(a), Grid(b)) => a.partial_cmp(b),
+            _ => None,
         }
     }
 }
 
+impl Hash for Solution {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if let Some(number) = self.number() {
+            match number {
+                Number::Int(x) => (0u8, x).hash(state),
+                Number::Float(x) => (1u8, canonical(x).to_bits()).hash(state),
+            }
+            return;
+        }
+        match self {
+            Bool(x) => (2u8, x).hash(state),
+            Str(x) => (3u8, x).hash(state),
+            List(x) => (4u8, x).hash(state),
+            Grid(x) => (5u8, x).hash(state),
+            _ => unreachable!("numbers are hashed above"),
+        }
+    }
+}
+
+/// Parses an answer back from its displayed form: integers (as `I64`, `U64`,
+/// `I128` or `U128`, whichever fits first), decimal numbers, `true`/`false`
+/// and multi-line grids. Anything else is kept as a string, including
+/// comma-separated text, which cannot be told apart from a `List`.
+impl FromStr for Solution {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if s.contains('\n') {
+            return Ok(Grid(s.lines().map(|line| line.chars().collect()).collect()));
+        }
+        let numeric = s.starts_with(|c: char| c.is_ascii_digit() || c == '-')
+            && s.chars().all(|c| c.is_ascii_digit() || "-+.eE".contains(c));
+        let parsed = match s {
+            "true" => Some(Bool(true)),
+            "false" => Some(Bool(false)),
+            _ if numeric => (s.parse().ok().map(I64))
+                .or_else(|| s.parse().ok().map(U64))
+                .or_else(|| s.parse().ok().map(I128))
+                .or_else(|| s.parse().ok().map(U128))
+                .or_else(|| s.parse().ok().map(F64)),
+            _ => None,
+        };
+        Ok(parsed.unwrap_or_else(|| Str(s.to_string())))
+    }
+}
+
 macro_rules! impl_from {
-    ($type_:ident, $kind_:ident) => {
+    ($type_:ty, $kind_:ident) => {
         impl From<$type_> for Solution {
             fn from(sol: $type_) -> Self {
                 Self::$kind_(sol)
@@ -60,10 +291,95 @@ impl_from!(u32, U32);
 impl_from!(u64, U64);
 impl_from!(u128, U128);
 impl_from!(usize, Usize);
+impl_from!(f64, F64);
+impl_from!(bool, Bool);
 impl_from!(String, Str);
+impl_from!(Vec<Solution>, List);
+impl_from!(Vec<Vec<char>>, Grid);
 
 impl From<&str> for Solution {
     fn from(sol: &str) -> Self {
         Self::Str(sol.to_owned())
     }
 }
+
+#[test]
+fn test_display_list() {
+    let list = Solution::from(vec![Solution::from(1u8), Solution::from("b"), Solution::from(true)]);
+    assert_eq!("1,b,true", list.to_string());
+    assert_eq!("list", list.type_name());
+}
+
+#[test]
+fn test_display_grid() {
+    let grid = Solution::from(vec![vec!['#', '.'], vec!['.', '#']]);
+    assert_eq!("#.\n.#", grid.to_string());
+    assert_eq!("grid", grid.type_name());
+    assert_eq!("2.5", Solution::from(2.5).to_string());
+}
+
+#[test]
+fn test_numeric_equality() {
+    assert_eq!(Solution::U64(5), Solution::Usize(5));
+    assert_eq!(Solution::I8(-3), Solution::I128(-3));
+    assert_eq!(Solution::F64(5.0), Solution::U32(5));
+    assert_eq!(Solution::U128(u128::MAX), Solution::U128(u128::MAX));
+    assert_ne!(Solution::I64(-1), Solution::U64(u64::MAX));
+    assert_ne!(Solution::from(1u8), Solution::from(true));
+    assert_ne!(Solution::from(1u8), Solution::from("1"));
+    assert_eq!(Solution::F64(f64::NAN), Solution::F64(f64::NAN));
+    assert_eq!(
+        Solution::from(vec![Solution::U8(1), Solution::from("a")]),
+        Solution::from(vec![Solution::I64(1), Solution::from("a")])
+    );
+}
+
+#[test]
+fn test_ordering() {
+    assert!(Solution::I8(-1) < Solution::U8(0));
+    assert!(Solution::U128(u128::MAX) > Solution::I128(i128::MAX));
+    assert!(Solution::F64(2.5) > Solution::U64(2));
+    assert!(Solution::F64(2.5) < Solution::I32(3));
+    assert!(Solution::F64(f64::INFINITY) > Solution::U128(u128::MAX));
+    assert_eq!(None, Solution::from(1u8).partial_cmp(&Solution::from("1")));
+}
+
+#[test]
+fn test_hash_matches_equality() {
+    use std::collections::HashSet;
+    let set: HashSet<Solution> = [Solution::U64(5), Solution::Usize(5), Solution::F64(5.0), Solution::I8(-5)]
+        .into_iter()
+        .collect();
+    assert_eq!(2, set.len());
+    assert!(set.contains(&Solution::I128(-5)));
+}
+
+#[test]
+fn test_from_str() {
+    let parse = |s: &str| s.parse::<Solution>().unwrap();
+    assert_eq!("i64", parse("-42").type_name());
+    assert_eq!("u64", parse("18446744073709551615").type_name());
+    assert_eq!("u128", parse("340282366920938463463374607431768211455").type_name());
+    assert_eq!(Solution::F64(2.5), parse("2.5"));
+    assert_eq!(Solution::Bool(false), parse("false"));
+    assert_eq!(Solution::from("abc"), parse("abc"));
+    assert_eq!(Solution::from("a,b"), parse("a,b"));
+    assert_eq!(Solution::from("-"), parse("-"));
+    assert_eq!(Solution::from(vec![vec!['#', '.'], vec!['.', '#']]), parse("#.\n.#"));
+    for answer in [Solution::Usize(554003), Solution::I16(-7), Solution::F64(0.125)] {
+        assert_eq!(answer, parse(&answer.to_string()));
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_keeps_variant() {
+    let answers = [Solution::Usize(5), Solution::F64(2.5), Solution::from(vec![Solution::I8(-1)])];
+    for answer in answers {
+        let json = serde_json::to_string(&answer).unwrap();
+        let parsed: Solution = serde_json::from_str(&json).unwrap();
+        assert_eq!(answer.type_name(), parsed.type_name());
+        assert_eq!(answer, parsed);
+    }
+    assert_eq!("{\"Usize\":5}", serde_json::to_string(&Solution::Usize(5)).unwrap());
+}
@@ -0,0 +1,164 @@
+use crate::{Solution, SolveError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A day split into stages that can be run and timed separately: the input is
+/// parsed once and then shared by both parts.
+pub trait Solver {
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError>;
+    fn part1(input: &Self::Input<'_>) -> Result<Solution, SolveError>;
+    fn part2(input: &Self::Input<'_>) -> Result<Solution, SolveError>;
+}
+
+pub struct PartRun {
+    pub answer: Result<Solution, SolveError>,
+    pub elapsed: Duration,
+}
+
+pub struct DayRun {
+    pub parse: Duration,
+    pub part1: Option<PartRun>,
+    pub part2: Option<PartRun>,
+}
+
+impl DayRun {
+    pub fn total(&self) -> Duration {
+        let parts = [&self.part1, &self.part2];
+        self.parse + parts.iter().flat_map(|p| p.as_ref()).map(|p| p.elapsed).sum::<Duration>()
+    }
+}
+
+/// Type-erased entry point used by the runner, e.g. `run::<Day05>`.
+pub type DayRunner = fn(&str, Option<Part>) -> Result<DayRun, SolveError>;
+
+/// A registered day: its solver plus the metadata the runner lists. Each day
+/// module declares one as `INFO`, and its year's `days!` list collects them.
+#[derive(Clone, Copy)]
+pub struct DayInfo {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    /// Parts with a real solution; a day with none is a stub.
+    pub parts: &'static [Part],
+    pub runner: DayRunner,
+}
+
+impl DayInfo {
+    pub const fn new<S: Solver>(year: u16, day: u8, title: &'static str, parts: &'static [Part]) -> DayInfo {
+        DayInfo {
+            year,
+            day,
+            title,
+            parts,
+            runner: run::<S>,
+        }
+    }
+
+    pub fn is_stub(&self) -> bool {
+        self.parts.is_empty()
+    }
+}
+
+/// Declares the day modules of a year and registers their `INFO` in that
+/// year's `DAYS`, so adding a day only takes its module file and one name in
+/// the list.
+#[macro_export]
+macro_rules! days {
+    ($($day:ident),* $(,)?) => {
+        $(pub mod $day;)*
+
+        /// Every registered day of the year, in the order listed.
+        pub const DAYS: &[$crate::etc::solver::DayInfo] = &[$($day::INFO),*];
+    };
+}
+
+/// Parses `input` and runs the selected part(s), timing each stage. A parse
+/// failure aborts the day; a failing part is reported in its `PartRun`.
+pub fn run<S: Solver>(input: &str, part: Option<Part>) -> Result<DayRun, SolveError> {
+    let time = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = time.elapsed();
+
+    let run_part = |wanted: Part| {
+        if part.is_some_and(|p| p != wanted) {
+            return None;
+        }
+        let time = Instant::now();
+        let answer = match wanted {
+            Part::One => S::part1(&parsed),
+            Part::Two => S::part2(&parsed),
+        };
+        Some(PartRun {
+            answer,
+            elapsed: time.elapsed(),
+        })
+    };
+
+    Ok(DayRun {
+        parse,
+        part1: run_part(Part::One),
+        part2: run_part(Part::Two),
+    })
+}
+
+#[cfg(test)]
+struct Sum;
+
+#[cfg(test)]
+impl Solver for Sum {
+    type Input<'a> = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        Ok(input.split(',').map(|s| s.parse()).collect::<Result<_, _>>()?)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        Ok(Solution::from(input.iter().sum::<u64>()))
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        Err(SolveError::no_answer("not solved"))
+    }
+}
+
+#[test]
+fn test_run_selected_part() {
+    let run = run::<Sum>("1,2,3", Some(Part::One)).unwrap();
+    assert_eq!(Solution::Usize(6), run.part1.unwrap().answer.unwrap());
+    assert!(run.part2.is_none());
+}
+
+#[test]
+fn test_run_reports_stage_errors() {
+    assert!(run::<Sum>("1,x", None).is_err());
+    let run = run::<Sum>("1,2", None).unwrap();
+    assert!(run.part2.unwrap().answer.is_err());
+}
+
+#[test]
+fn test_day_info() {
+    const INFO: DayInfo = DayInfo::new::<Sum>(2023, 1, "Sum", &[Part::One]);
+    assert!(!INFO.is_stub());
+    let run = (INFO.runner)("4,5", Some(Part::One)).unwrap();
+    assert_eq!(Solution::U8(9), run.part1.unwrap().answer.unwrap());
+}
@@ -0,0 +1,272 @@
+use super::fetch::FetchError;
+use super::solver::Part;
+use super::Solution;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter, Result};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Every submitted answer and the site's response, relative to the working
+/// directory.
+pub const HISTORY_FILE: &str = "submissions.txt";
+
+/// How the site judged a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Response {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about which way.
+    Wrong,
+    /// Submitted before the cooldown of a previous wrong answer ran out.
+    TooRecent,
+    /// The part was already solved or is not unlocked yet.
+    WrongLevel,
+    Unknown,
+}
+
+impl Response {
+    /// Reads the response out of the page the site returns after a submission.
+    pub fn from_page(page: &str) -> Response {
+        if page.contains("That's the right answer") {
+            Response::Correct
+        } else if page.contains("your answer is too high") {
+            Response::TooHigh
+        } else if page.contains("your answer is too low") {
+            Response::TooLow
+        } else if page.contains("That's not the right answer") {
+            Response::Wrong
+        } else if page.contains("You gave an answer too recently") {
+            Response::TooRecent
+        } else if page.contains("You don't seem to be solving the right level") {
+            Response::WrongLevel
+        } else {
+            Response::Unknown
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Response::Correct => "correct",
+            Response::TooHigh => "too-high",
+            Response::TooLow => "too-low",
+            Response::Wrong => "wrong",
+            Response::TooRecent => "too-recent",
+            Response::WrongLevel => "wrong-level",
+            Response::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for Response {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let text = match self {
+            Response::Correct => "correct",
+            Response::TooHigh => "wrong, too high",
+            Response::TooLow => "wrong, too low",
+            Response::Wrong => "wrong",
+            Response::TooRecent => "answered too recently, wait before trying again",
+            Response::WrongLevel => "this part is already solved or not unlocked yet",
+            Response::Unknown => "unrecognised response",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl FromStr for Response {
+    type Err = ();
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        [
+            Response::Correct,
+            Response::TooHigh,
+            Response::TooLow,
+            Response::Wrong,
+            Response::TooRecent,
+            Response::WrongLevel,
+            Response::Unknown,
+        ]
+        .into_iter()
+        .find(|response| response.name() == s)
+        .ok_or(())
+    }
+}
+
+/// Something that can post an answer and return the page the site responds
+/// with. Closures work too, which is handy for tests.
+pub trait Submitter: Sync {
+    fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> std::result::Result<String, FetchError>;
+}
+
+impl<F> Submitter for F
+where
+    F: Fn(u16, u8, Part, &str) -> std::result::Result<String, FetchError> + Sync,
+{
+    fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> std::result::Result<String, FetchError> {
+        self(year, day, part, answer)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub answer: Solution,
+    pub response: Response,
+}
+
+/// The submission history, one `year day part response answer` line per
+/// attempt, oldest first.
+#[derive(Debug, Default, PartialEq)]
+pub struct History {
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Loads the history; a missing file is an empty history.
+    pub fn load(path: &Path) -> io::Result<History> {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents.parse(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn record(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    /// Why `answer` should not be submitted, given the earlier responses: the
+    /// part is solved, the answer was already rejected, or it lies outside a
+    /// bound set by a "too high" or "too low" response.
+    pub fn refusal(&self, year: u16, day: u8, part: Part, answer: &Solution) -> Option<String> {
+        let attempts = self
+            .attempts
+            .iter()
+            .filter(|a| (a.year, a.day, a.part) == (year, day, part));
+        for attempt in attempts {
+            let previous = &attempt.answer;
+            let reason = match (attempt.response, answer.partial_cmp(previous)) {
+                (Response::Correct, _) => format!("already solved with {}", previous),
+                (Response::TooHigh | Response::TooLow | Response::Wrong, Some(Ordering::Equal)) => {
+                    format!("{} was already rejected ({})", answer, attempt.response)
+                }
+                (Response::TooHigh, Some(Ordering::Greater)) => {
+                    format!("{} is too high: {} already was", answer, previous)
+                }
+                (Response::TooLow, Some(Ordering::Less)) => {
+                    format!("{} is too low: {} already was", answer, previous)
+                }
+                _ => continue,
+            };
+            return Some(reason);
+        }
+        None
+    }
+}
+
+impl FromStr for History {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut history = History::default();
+        for line in s.lines().filter(|line| !line.trim().is_empty()) {
+            let invalid = || {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid submission entry: {:?}", line),
+                )
+            };
+            let mut fields = line.splitn(5, ' ');
+            let mut field = || fields.next().ok_or_else(invalid);
+            let year = field()?.parse().map_err(|_| invalid())?;
+            let day = field()?.parse().map_err(|_| invalid())?;
+            let part = match field()? {
+                "1" => Part::One,
+                "2" => Part::Two,
+                _ => return Err(invalid()),
+            };
+            let response = field()?.parse().map_err(|_| invalid())?;
+            let Ok(answer) = field()?.parse();
+            history.record(Attempt {
+                year,
+                day,
+                part,
+                answer,
+                response,
+            });
+        }
+        Ok(history)
+    }
+}
+
+impl Display for History {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for a in &self.attempts {
+            writeln!(f, "{} {:02} {} {} {}", a.year, a.day, a.part, a.response.name(), a.answer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+fn attempt(answer: u64, response: Response) -> Attempt {
+    Attempt {
+        year: 2023,
+        day: 5,
+        part: Part::One,
+        answer: Solution::from(answer),
+        response,
+    }
+}
+
+#[test]
+fn test_response_from_page() {
+    let page = "<article><p>That's not the right answer; your answer is too high.</p></article>";
+    assert_eq!(Response::TooHigh, Response::from_page(page));
+    assert_eq!(Response::Correct, Response::from_page("<p>That's the right answer!</p>"));
+    assert_eq!(Response::Wrong, Response::from_page("<p>That's not the right answer.</p>"));
+    assert_eq!(Response::Unknown, Response::from_page("<html></html>"));
+}
+
+#[test]
+fn test_history_round_trip() {
+    let mut history = History::default();
+    history.record(attempt(100, Response::TooHigh));
+    history.record(Attempt {
+        answer: Solution::from("a b,c"),
+        ..attempt(0, Response::Wrong)
+    });
+    assert_eq!("2023 05 1 too-high 100\n2023 05 1 wrong a b,c\n", history.to_string());
+    assert_eq!(history, history.to_string().parse().unwrap());
+    assert!("2023 05 3 wrong 1".parse::<History>().is_err());
+    assert!("2023 05 1 maybe 1".parse::<History>().is_err());
+}
+
+#[test]
+fn test_refusal() {
+    let mut history = History::default();
+    history.record(attempt(100, Response::TooHigh));
+    history.record(attempt(20, Response::TooLow));
+    history.record(attempt(50, Response::Wrong));
+    let refusal = |answer: u64| history.refusal(2023, 5, Part::One, &Solution::from(answer));
+    assert!(refusal(100).is_some());
+    assert!(refusal(150).is_some());
+    assert!(refusal(20).is_some());
+    assert!(refusal(5).is_some());
+    assert!(refusal(50).is_some());
+    assert_eq!(None, refusal(60));
+    assert_eq!(None, history.refusal(2023, 5, Part::Two, &Solution::from(150u64)));
+    assert_eq!(None, history.refusal(2023, 5, Part::One, &Solution::from("x")));
+
+    history.record(attempt(60, Response::Correct));
+    let refusal = history.refusal(2023, 5, Part::One, &Solution::from(60u64));
+    assert!(refusal.unwrap().contains("already solved"));
+}
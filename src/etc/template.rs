@@ -0,0 +1,187 @@
+use super::examples;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Directory holding the `yYYYY` modules, relative to the working directory.
+pub const SRC_DIR: &str = "src";
+
+pub fn year_dir(year: u16) -> PathBuf {
+    Path::new(SRC_DIR).join(format!("y{}", year))
+}
+
+pub fn module_path(year: u16, day: u8) -> PathBuf {
+    year_dir(year).join(format!("day{:02}.rs", day))
+}
+
+/// Source of a new stub day: a `Solver` whose stages still return zero and its
+/// `INFO` entry. Its examples are tested from the fixtures, see `examples`.
+pub fn module_source(year: u16, day: u8, title: &str) -> String {
+    let name = format!("Day{:02}", day);
+    format!(
+        r#"use crate::{{DayInfo, Solution, SolveError, Solver}};
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub const INFO: DayInfo = DayInfo::new::<{name}>({year}, {day}, {title:?}, &[]);
+
+pub struct {name};
+
+impl Solver for {name} {{
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {{
+        // Your solution here...
+        Ok(input)
+    }}
+
+    fn part1(_input: &Self::Input<'_>) -> Result<Solution, SolveError> {{
+        let sol1: u64 = 0;
+        Ok(Solution::from(sol1))
+    }}
+
+    fn part2(_input: &Self::Input<'_>) -> Result<Solution, SolveError> {{
+        let sol2: u64 = 0;
+        Ok(Solution::from(sol2))
+    }}
+}}
+"#
+    )
+}
+
+/// Sidecar of a new example; its answers only count once uncommented.
+const SIDECAR: &str = "# Answers given in the puzzle text for this example.\n# part1 = 0\n# part2 = 0\n";
+
+/// Adds `dayNN` to the `days!` list in a year's `mod.rs`, keeping the list
+/// sorted and five names per line. Returns `None` if it is already there.
+pub fn register_day(mod_source: &str, day: u8) -> Option<String> {
+    let start = mod_source.find("days! {")? + "days! {".len();
+    let end = start + mod_source[start..].find('}')?;
+
+    let module = format!("day{:02}", day);
+    let mut names: Vec<&str> = mod_source[start..end]
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|name| !name.is_empty())
+        .collect();
+    if names.contains(&module.as_str()) {
+        return None;
+    }
+    names.push(&module);
+    names.sort();
+
+    let lines: Vec<String> = names
+        .chunks(5)
+        .map(|chunk| format!("    {},\n", chunk.join(", ")))
+        .collect();
+    Some(format!("{}\n{}{}", &mod_source[..start], lines.concat(), &mod_source[end..]))
+}
+
+/// Fails if `path` exists with anything but `source`, so a module that was
+/// edited since it was generated is never overwritten.
+fn check_unedited(path: &Path, source: &str) -> io::Result<()> {
+    match fs::read_to_string(path) {
+        Ok(existing) if existing != source => Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} has been edited; remove it to start over", path.display()),
+        )),
+        Ok(_) => Ok(()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Writes the module for a new day, registers it and creates an empty first
+/// example with a sidecar for its answers. `implemented` is whether the day is
+/// already registered with solved parts; such a day, or a module file that
+/// differs from the template, is never overwritten. Returns the files touched.
+pub fn create_day(year: u16, day: u8, title: &str, implemented: bool) -> io::Result<Vec<PathBuf>> {
+    let refuse = |msg: String| Err(io::Error::new(io::ErrorKind::AlreadyExists, msg));
+
+    let mod_path = year_dir(year).join("mod.rs");
+    let mod_source = fs::read_to_string(&mod_path).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => io::Error::new(
+            err.kind(),
+            format!(
+                "{} is not set up: add {} with a `days!` list and register it in `registry::YEARS`",
+                year,
+                mod_path.display()
+            ),
+        ),
+        _ => io::Error::new(err.kind(), format!("{}: {}", mod_path.display(), err)),
+    })?;
+    let registered = register_day(&mod_source, day);
+
+    let path = module_path(year, day);
+    if implemented {
+        return refuse(format!("day {} of {} is already implemented", day, year));
+    }
+    let source = module_source(year, day, title);
+    check_unedited(&path, &source)?;
+
+    let mut touched = Vec::new();
+    fs::write(&path, source)?;
+    touched.push(path);
+    if let Some(source) = registered {
+        fs::write(&mod_path, source)?;
+        touched.push(mod_path);
+    }
+
+    let example = examples::example_path(year, day, 1);
+    if !example.exists() {
+        fs::create_dir_all(examples::examples_dir(year))?;
+        fs::write(&example, "")?;
+        touched.push(example.clone());
+        let sidecar = example.with_extension("toml");
+        fs::write(&sidecar, SIDECAR)?;
+        touched.push(sidecar);
+    }
+    Ok(touched)
+}
+
+#[test]
+fn test_module_source() {
+    let source = module_source(2023, 9, "Mirage Maintenance");
+    assert!(source.contains("DayInfo::new::<Day09>(2023, 9, \"Mirage Maintenance\", &[])"));
+    assert!(source.contains("impl Solver for Day09 {"));
+}
+
+#[test]
+fn test_register_day() {
+    let source = "crate::days! {\n    day01, day03,\n}\n\n#[test]\nfn test_days() {}\n";
+    let registered = register_day(source, 2).unwrap();
+    assert_eq!(
+        "crate::days! {\n    day01, day02, day03,\n}\n\n#[test]\nfn test_days() {}\n",
+        registered
+    );
+    assert_eq!(None, register_day(&registered, 3));
+
+    let full = (1..=6).fold("crate::days! {\n}\n".to_string(), |s, day| register_day(&s, day).unwrap());
+    assert_eq!(
+        "crate::days! {\n    day01, day02, day03, day04, day05,\n    day06,\n}\n",
+        full
+    );
+}
+
+#[test]
+fn test_check_unedited() {
+    let dir = super::fetch::temp_dir("template-unedited");
+    let path = dir.join("day10.rs");
+    let source = module_source(2023, 10, "Pipe Maze");
+    assert!(check_unedited(&path, &source).is_ok());
+    fs::write(&path, &source).unwrap();
+    assert!(check_unedited(&path, &source).is_ok());
+
+    fs::write(&path, source.replace("Ok(input)", "Ok(input.trim())")).unwrap();
+    let err = check_unedited(&path, &source).unwrap_err();
+    assert_eq!(io::ErrorKind::AlreadyExists, err.kind());
+    assert!(err.to_string().contains("has been edited"));
+}
+
+#[test]
+fn test_unregistered_year() {
+    assert!(crate::registry::days(2015).is_none());
+    let err = create_day(2015, 1, "", false).unwrap_err();
+    assert_eq!(io::ErrorKind::NotFound, err.kind());
+    assert!(err.to_string().contains("2015 is not set up"));
+    assert!(!module_path(2015, 1).exists());
+}
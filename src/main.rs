@@ -1,74 +1,495 @@
-mod days;
 mod etc;
+mod registry;
+mod y2023;
 
-use etc::solution::Solution;
-use days::{day01, day02, day03, day04, day05,
-           day06, day07, day08, day09, day10,
-           day11, day12, day13, day14, day15,
-           day16, day17, day18, day19, day20,
-           day21, day22, day23, day24, day25};
+use etc::answers::{self, Answers, Verdict};
+use etc::bench::{self, Baseline};
+use etc::cli::{self, CliError, Command, Options};
+use etc::report::{self, Format, RunReport};
+use etc::submit::{self, Attempt, History, Response, Submitter};
+use etc::solver::{DayInfo, DayRun, DayRunner, PartRun};
+use etc::fetch::HttpFetcher;
+use etc::input::InputProvider;
+use etc::{examples, input, template, Part, Solution, SolveError, Solver};
+use rayon::prelude::*;
 use std::env;
-use std::time::Instant;
-
-pub type SolutionPair = (Solution, Solution);
+use std::path::Path;
+use std::process;
+use std::time::{Duration, Instant};
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    if args.len() < 2 {
-        panic!("Please provide the day(s) to run as a command-line argument.");
+    let options = match cli::parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(CliError::Help) => {
+            println!("{}", CliError::Help);
+            return;
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(2);
+        }
+    };
+    if options.command != Command::New && registry::days(options.year).is_none() {
+        eprintln!("error: no days are registered for {}", options.year);
+        process::exit(2);
     }
 
-    let days: Vec<u8> = args[1..].iter()
-        .map(|x| x.parse().unwrap_or_else(|v| panic!("Not a valid day: {}", v)))
-        .collect();
+    match options.command {
+        Command::Run => run_days(&options, &provider()),
+        Command::Bench => bench_days(&options, &provider()),
+        Command::List => list_days(&options),
+        Command::New => new_day(&options),
+        Command::Submit => submit_answer(&options, &provider()),
+    }
+}
 
+/// Inputs come from the input directory, downloading the missing ones.
+type Provider = InputProvider<HttpFetcher>;
+
+fn provider() -> Provider {
+    InputProvider::new(input::input_dir(), HttpFetcher::from_env())
+}
+
+fn run_days(options: &Options, provider: &Provider) {
+    let answers_path = Path::new(answers::ANSWERS_FILE);
+    let mut answers = match Answers::load(answers_path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("error: could not read {}: {}", answers_path.display(), err);
+            process::exit(1);
+        }
+    };
+    // Recorded answers only apply to the puzzle inputs.
+    let verify = options.input.is_none() && !options.example;
+    let human = options.format == Format::Human;
+    let verbose = human && !options.quiet;
+    let mut reports = Vec::new();
     let mut runtime = 0.0;
 
-    for day in days {
-        let func = get_day_solver(day);
+    let mut report_day = |day: u8, result: Result<DayRun, String>| {
+        if verbose {
+            println!("\n{}", header(options.year, day));
+        }
+        let selected = [Part::One, Part::Two]
+            .into_iter()
+            .filter(|&part| options.part.is_none_or(|p| p == part));
+        match result {
+            Ok(run) => {
+                let total = run.total();
+                if verbose {
+                    println!("  · Parse: {:.4} ms", as_ms(run.parse));
+                }
+                for (part, part_run) in [(Part::One, run.part1), (Part::Two, run.part2)] {
+                    let Some(part_run) = part_run else {
+                        continue;
+                    };
+                    let verdict = match &part_run.answer {
+                        Ok(answer) if verify => answers.check(options.year, day, part, answer),
+                        Ok(answer) if options.example => check_example(options.year, day, options.part, part, answer),
+                        _ => Verdict::Unknown,
+                    };
+                    if human {
+                        print_part(options, part, &part_run, &verdict);
+                    }
+                    if let (Ok(answer), true) = (&part_run.answer, options.record) {
+                        answers.record(options.year, day, part, answer);
+                    }
+                    reports.push(RunReport {
+                        year: options.year,
+                        day,
+                        part,
+                        answer: part_run.answer.map_err(|err| err.to_string()),
+                        parse: run.parse,
+                        elapsed: part_run.elapsed,
+                        verdict,
+                    });
+                }
+                if verbose {
+                    println!("  · Elapsed: {:.4} ms", as_ms(total));
+                }
+                runtime += as_ms(total);
+            }
+            Err(err) => {
+                if human {
+                    println!("  · Error: {}", err);
+                }
+                reports.extend(selected.map(|part| RunReport {
+                    year: options.year,
+                    day,
+                    part,
+                    answer: Err(err.clone()),
+                    parse: Duration::ZERO,
+                    elapsed: Duration::ZERO,
+                    verdict: Verdict::Unknown,
+                }));
+            }
+        }
+    };
 
-        let time = Instant::now();
-        let (p1, p2) = func();
-        let elapsed_ms = time.elapsed().as_nanos() as f64 / 1_000_000.0;
-        
-        println!("\n=== Day {:02} ===", day);
-        println!("  · Part 1: {}", p1);
-        println!("  · Part 2: {}", p2);
-        println!("  · Elapsed: {:.4} ms", elapsed_ms);
+    let wall_clock = Instant::now();
+    if options.parallel {
+        // Solve everything on the rayon pool first, then report in day order.
+        let results: Vec<_> = options
+            .days
+            .par_iter()
+            .map(|&day| (day, run_day(day, options, provider)))
+            .collect();
+        for (day, result) in results {
+            report_day(day, result);
+        }
+    } else {
+        for &day in &options.days {
+            report_day(day, run_day(day, options, provider));
+        }
+    }
+    let wall_clock = wall_clock.elapsed();
 
-        runtime += elapsed_ms;
+    if verbose {
+        println!("Total runtime: {:.4} ms", runtime);
+        if options.parallel {
+            println!("Wall-clock time: {:.4} ms", as_ms(wall_clock));
+        }
+    }
+    if !human {
+        print!("{}", report::render(options.format, &reports));
     }
 
-    println!("Total runtime: {:.4} ms", runtime);
+    if options.record {
+        if let Err(err) = answers.save(answers_path) {
+            eprintln!("error: could not write {}: {}", answers_path.display(), err);
+            process::exit(1);
+        }
+        if verbose {
+            println!("Answers recorded in {}", answers_path.display());
+        }
+    }
 }
 
-fn get_day_solver(day: u8) -> fn() -> SolutionPair {
-    match day {
-         1 => day01::solve,
-         2 => day02::solve,
-         3 => day03::solve,
-         4 => day04::solve,
-         5 => day05::solve,
-         6 => day06::solve,
-         7 => day07::solve,
-         8 => day08::solve,
-         9 => day09::solve,
-        10 => day10::solve,
-        11 => day11::solve,
-        12 => day12::solve,
-        13 => day13::solve,
-        14 => day14::solve,
-        15 => day15::solve,
-        16 => day16::solve,
-        17 => day17::solve,
-        18 => day18::solve,
-        19 => day19::solve,
-        20 => day20::solve,
-        21 => day21::solve,
-        22 => day22::solve,
-        23 => day23::solve,
-        24 => day24::solve,
-        25 => day25::solve,
-         _ => unimplemented!(),
-    }
-}
\ No newline at end of file
+/// Runs `day` `options.repeat` times. The returned answers come from the last
+/// run and every stage timing is the mean over all runs.
+fn run_day(day: u8, options: &Options, provider: &Provider) -> Result<DayRun, String> {
+    let (runner, input) = load_day(day, options, provider)?;
+
+    let mut parse = Duration::ZERO;
+    let mut part1 = Duration::ZERO;
+    let mut part2 = Duration::ZERO;
+    let mut last = None;
+    for _ in 0..options.repeat {
+        let run = runner(&input, options.part).map_err(|err| err.to_string())?;
+        parse += run.parse;
+        part1 += run.part1.as_ref().map_or(Duration::ZERO, |p| p.elapsed);
+        part2 += run.part2.as_ref().map_or(Duration::ZERO, |p| p.elapsed);
+        last = Some(run);
+    }
+
+    let mut run = last.ok_or("Nothing was run")?;
+    let repeat = options.repeat as u32;
+    run.parse = parse / repeat;
+    if let Some(p) = run.part1.as_mut() {
+        p.elapsed = part1 / repeat;
+    }
+    if let Some(p) = run.part2.as_mut() {
+        p.elapsed = part2 / repeat;
+    }
+    Ok(run)
+}
+
+fn bench_days(options: &Options, provider: &Provider) {
+    let mut baseline = match &options.baseline {
+        Some(path) => match Baseline::load(path) {
+            Ok(baseline) => Some(baseline),
+            Err(err) => {
+                eprintln!("error: could not read {}: {}", path.display(), err);
+                process::exit(1);
+            }
+        },
+        None => None,
+    };
+
+    for &day in &options.days {
+        println!("\n{}", header(options.year, day));
+        let result = load_day(day, options, provider).and_then(|(runner, input)| {
+            bench::bench_day(runner, &input, options.part, options.warmup, options.samples)
+                .map_err(|err| err.to_string())
+        });
+        let stages = match result {
+            Ok(stages) => stages,
+            Err(err) => {
+                println!("  · Error: {}", err);
+                continue;
+            }
+        };
+
+        for (stage, stats) in stages {
+            let change = baseline
+                .as_ref()
+                .and_then(|b| b.get(options.year, day, stage))
+                .map(|old| match bench::percent_change(old, stats.median) {
+                    Some(change) => format!(" ({:+.1}%)", change),
+                    None => " (n/a)".to_string(),
+                })
+                .unwrap_or_default();
+            println!(
+                "  · {:<6} min {:.4} ms, median {:.4} ms, mean {:.4} ms, stddev {:.4} ms{}",
+                stage,
+                as_ms(stats.min),
+                as_ms(stats.median),
+                as_ms(stats.mean),
+                as_ms(stats.stddev),
+                change
+            );
+            if let Some(baseline) = baseline.as_mut() {
+                baseline.insert(options.year, day, stage, stats.median);
+            }
+        }
+    }
+
+    if let (Some(path), Some(baseline)) = (&options.baseline, &baseline) {
+        if let Err(err) = baseline.save(path) {
+            eprintln!("error: could not write {}: {}", path.display(), err);
+            process::exit(1);
+        }
+        println!("\nBaseline saved to {}", path.display());
+    }
+}
+
+/// Looks up the solver for `day` and reads the input selected by `options`.
+fn load_day(day: u8, options: &Options, provider: &Provider) -> Result<(DayRunner, String), String> {
+    let info = registry::find(options.year, day)
+        .ok_or_else(|| format!("Day {} of {} is not available", day, options.year))?;
+    if info.is_stub() {
+        return Err(format!("Day {} is not implemented yet", day));
+    }
+
+    let input = match (&options.input, options.example) {
+        (Some(path), _) => input::read_input(path).map_err(|err| err.to_string())?,
+        (None, true) => {
+            let examples = examples::load_examples(options.year, day).map_err(|err| err.to_string())?;
+            let example = examples::select(examples, options.part).ok_or_else(|| match options.part {
+                Some(part) => format!("no example of day {} has an answer for part {}", day, part),
+                None => {
+                    let path = examples::example_path(options.year, day, 1);
+                    format!("example not found: {}", path.display())
+                }
+            })?;
+            example.input
+        }
+        (None, false) => provider.get(options.year, day).map_err(|err| err.to_string())?,
+    };
+
+    Ok((info.runner, input))
+}
+
+/// Checks an answer against the sidecar of the example `load_day` ran it on,
+/// which depends on the `selected` part.
+fn check_example(year: u16, day: u8, selected: Option<Part>, part: Part, answer: &Solution) -> Verdict {
+    let examples = examples::load_examples(year, day).unwrap_or_default();
+    let example = examples::select(examples, selected);
+    let expected = (example.iter())
+        .flat_map(|example| &example.expected)
+        .find(|(p, _)| *p == part);
+    match expected {
+        Some((_, expected)) if expected == answer => Verdict::Correct,
+        Some((_, expected)) => Verdict::Wrong(expected.to_string()),
+        None => Verdict::Unknown,
+    }
+}
+
+fn list_days(options: &Options) {
+    println!("Advent of Code {}", options.year);
+    for &day in &options.days {
+        let Some(info) = registry::find(options.year, day) else {
+            println!("Day {:02}  (not registered)", day);
+            continue;
+        };
+        let status = if info.is_stub() {
+            "stub".to_string()
+        } else {
+            let parts: Vec<String> = info.parts.iter().map(Part::to_string).collect();
+            let label = if parts.len() == 1 { "part" } else { "parts" };
+            format!("{} {}", label, parts.join(", "))
+        };
+        println!("Day {:02}  {:<32} {}", day, info.title, status);
+    }
+    let stubs: Vec<String> = (options.days.iter())
+        .filter_map(|&day| registry::find(options.year, day))
+        .filter(|info| info.is_stub())
+        .map(|info| info.day.to_string())
+        .collect();
+    if !stubs.is_empty() {
+        println!("\nStubbed days: {}", stubs.join(", "));
+    }
+}
+
+fn new_day(options: &Options) {
+    let (year, day) = (options.year, options.days[0]);
+    let registered = registry::find(year, day);
+    let title = registered.map_or("", |info| info.title);
+    let implemented = registered.is_some_and(|info| !info.is_stub());
+    match template::create_day(year, day, title, implemented) {
+        Ok(touched) => {
+            for path in touched {
+                println!("Wrote {}", path.display());
+            }
+        }
+        Err(err) => {
+            eprintln!("error: could not create day {} of {}: {}", day, year, err);
+            process::exit(1);
+        }
+    }
+}
+
+/// Solves one part on the puzzle input and posts the answer, unless the
+/// submission history already rules it out.
+fn submit_answer(options: &Options, provider: &Provider) {
+    let (year, day) = (options.year, options.days[0]);
+    let part = options.part.unwrap_or(Part::One);
+    let run = match run_day(day, options, provider) {
+        Ok(run) => run,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    };
+    let part_run = match part {
+        Part::One => run.part1,
+        Part::Two => run.part2,
+    };
+    let answer = match part_run.map(|p| p.answer) {
+        Some(Ok(answer)) if !answer.to_string().contains('\n') => answer,
+        Some(Ok(_)) => {
+            eprintln!("error: multi-line answers cannot be submitted");
+            process::exit(1);
+        }
+        Some(Err(err)) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+        None => unreachable!("the selected part always runs"),
+    };
+
+    let history_path = Path::new(submit::HISTORY_FILE);
+    let mut history = match History::load(history_path) {
+        Ok(history) => history,
+        Err(err) => {
+            eprintln!("error: could not read {}: {}", history_path.display(), err);
+            process::exit(1);
+        }
+    };
+    if let Some(reason) = history.refusal(year, day, part, &answer) {
+        eprintln!("Not submitting {}: {}", answer, reason);
+        process::exit(1);
+    }
+
+    println!("Submitting {} for {} day {} part {}", answer, year, day, part);
+    let page = match provider.fetcher().submit(year, day, part, &answer.to_string()) {
+        Ok(page) => page,
+        Err(err) => {
+            eprintln!("error: could not submit: {}", err);
+            process::exit(1);
+        }
+    };
+    let response = Response::from_page(&page);
+    println!("Response: {}", response);
+
+    if response == Response::Correct {
+        let answers_path = Path::new(answers::ANSWERS_FILE);
+        let saved = Answers::load(answers_path).and_then(|mut answers| {
+            answers.record(year, day, part, &answer);
+            answers.save(answers_path)
+        });
+        if let Err(err) = saved {
+            eprintln!("error: could not write {}: {}", answers_path.display(), err);
+        }
+    }
+    history.record(Attempt {
+        year,
+        day,
+        part,
+        answer,
+        response,
+    });
+    if let Err(err) = history.save(history_path) {
+        eprintln!("error: could not write {}: {}", history_path.display(), err);
+        process::exit(1);
+    }
+}
+
+fn print_part(options: &Options, part: Part, run: &PartRun, verdict: &Verdict) {
+    let mark = match verdict {
+        Verdict::Unknown => String::new(),
+        _ => format!(" {}", verdict),
+    };
+    match (&run.answer, options.quiet) {
+        (Ok(answer), true) => println!("{}{}", answer, mark),
+        (Ok(answer), false) => {
+            let text = answer.to_string();
+            if text.contains('\n') {
+                // Grids and other multi-line answers start on their own line.
+                println!("  · Part {}:{} ({:.4} ms)", part, mark, as_ms(run.elapsed));
+                for line in text.lines() {
+                    println!("      {}", line);
+                }
+            } else {
+                println!("  · Part {}: {}{} ({:.4} ms)", part, text, mark, as_ms(run.elapsed));
+            }
+        }
+        (Err(err), _) => println!("  · Part {}: Error: {}", part, err),
+    }
+}
+
+fn header(year: u16, day: u8) -> String {
+    match registry::find(year, day) {
+        Some(info) => format!("=== {} Day {:02}: {} ===", info.year, day, info.title),
+        None => format!("=== Day {:02} ===", day),
+    }
+}
+
+fn as_ms(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1_000_000.0
+}
+
+#[test]
+fn test_recorded_answers() {
+    let answers = Answers::load(Path::new(answers::ANSWERS_FILE)).unwrap();
+    let offline = |_, _| Err(etc::fetch::FetchError::Transport("offline".to_string()));
+    let cached = InputProvider::new(input::input_dir(), offline);
+    for info in registry::YEARS.iter().flat_map(|&(_, days)| days) {
+        let (year, day) = (info.year, info.day);
+        // Inputs are not always checked in, so only verify the ones we have.
+        let Ok(input) = cached.get(year, day) else {
+            continue;
+        };
+        for part in [Part::One, Part::Two] {
+            let Some(expected) = answers.get(year, day, part) else {
+                continue;
+            };
+            let run = (info.runner)(&input, Some(part)).unwrap();
+            let part_run = match part {
+                Part::One => run.part1,
+                Part::Two => run.part2,
+            };
+            let answer = part_run.unwrap().answer.unwrap();
+            assert_eq!(expected, &answer, "{} day {} part {}", year, day, part);
+        }
+    }
+}
+
+#[test]
+fn test_examples() {
+    for info in registry::YEARS.iter().flat_map(|&(_, days)| days) {
+        let examples = examples::load_examples(info.year, info.day).unwrap();
+        for (index, example) in examples.iter().enumerate() {
+            for (part, expected) in &example.expected {
+                let run = (info.runner)(&example.input, Some(*part)).unwrap();
+                let part_run = match part {
+                    Part::One => run.part1,
+                    Part::Two => run.part2,
+                };
+                let answer = part_run.unwrap().answer.unwrap();
+                let (year, day) = (info.year, info.day);
+                assert_eq!(expected, &answer, "{} day {} example {} part {}", year, day, index + 1, part);
+            }
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::etc::solver::DayInfo;
+use crate::y2023;
+
+/// Every year with registered days. A new year is a `yYYYY` module using
+/// `days!` plus one entry here.
+pub const YEARS: &[(u16, &[DayInfo])] = &[(2023, y2023::DAYS)];
+
+pub fn days(year: u16) -> Option<&'static [DayInfo]> {
+    YEARS.iter().find(|&&(y, _)| y == year).map(|&(_, days)| days)
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static DayInfo> {
+    days(year)?.iter().find(|info| info.day == day)
+}
+
+#[test]
+fn test_find() {
+    assert_eq!(5, find(2023, 5).unwrap().day);
+    assert!(find(2023, 26).is_none());
+    assert!(find(2015, 1).is_none());
+    for &(year, days) in YEARS {
+        assert!(days.iter().all(|info| info.year == year));
+    }
+}
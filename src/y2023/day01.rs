@@ -1,11 +1,29 @@
-use crate::{Solution, SolutionPair};
+use crate::{DayInfo, Part, Solution, SolveError, Solver};
 
 ///////////////////////////////////////////////////////////////////////////////
-use std::str::FromStr;
 
-pub fn solve() -> SolutionPair {
-    let input = include_str!("../../input/input01.txt");
-    let sol2: usize = input
+pub const INFO: DayInfo = DayInfo::new::<Day01>(2023, 1, "Trebuchet?!", &[Part::One, Part::Two]);
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        Ok(Solution::from(solve_part1(input)))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        Ok(Solution::from(solve_part2(input)))
+    }
+}
+
+fn solve_part2(input: &str) -> usize {
+    input
         .lines()
         .map(|line| {
             line.replace("one", "o1e")
@@ -28,9 +46,11 @@ pub fn solve() -> SolutionPair {
             let last = *vec.last().unwrap_or(&0);
             first * 10 + last
         })
-        .sum();
+        .sum()
+}
 
-    let sol1: usize = input
+fn solve_part1(input: &str) -> usize {
+    input
         .lines()
         .map(|line| {
             line.chars()
@@ -43,7 +63,5 @@ pub fn solve() -> SolutionPair {
             let last = *vec.last().unwrap_or(&0);
             first * 10 + last
         })
-        .sum();
-
-    (Solution::from(sol1), Solution::from(sol2))
+        .sum()
 }
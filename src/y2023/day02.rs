@@ -1,9 +1,9 @@
-use crate::{Solution, SolutionPair};
-use std::{fs::read_to_string, str::FromStr};
+use crate::{DayInfo, Part, Solution, SolveError, Solver};
+use std::str::FromStr;
 
 ///////////////////////////////////////////////////////////////////////////////
 #[derive(Debug)]
-struct Game {
+pub struct Game {
     id: usize,
     rounds: Vec<Round>,
 }
@@ -68,25 +68,22 @@ impl Game {
 }
 
 impl FromStr for Round {
-    type Err = &'static str;
+    type Err = SolveError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut num_red = 0;
         let mut num_green = 0;
         let mut num_blue = 0;
         let colour_pairs: Vec<&str> = s.split(',').map(|s| s.trim()).collect();
         for pair in colour_pairs {
-            let mut iter = pair.splitn(2, ' ');
-            if let Some(quantity_str) = iter.next() {
-                if let Some(colour) = iter.next() {
-                    if let Ok(quanntity) = quantity_str.parse::<usize>() {
-                        match colour {
-                            "red" => num_red = quanntity,
-                            "green" => num_green = quanntity,
-                            "blue" => num_blue = quanntity,
-                            _ => (),
-                        }
-                    }
-                }
+            let (quantity_str, colour) = pair
+                .split_once(' ')
+                .ok_or_else(|| SolveError::parse(format!("Invalid colour count: {:?}", pair)))?;
+            let quantity = quantity_str.parse::<usize>()?;
+            match colour {
+                "red" => num_red = quantity,
+                "green" => num_green = quantity,
+                "blue" => num_blue = quantity,
+                _ => return Err(SolveError::parse(format!("Unknown colour: {:?}", colour))),
             }
         }
         Ok(Self {
@@ -98,55 +95,58 @@ impl FromStr for Round {
 }
 
 impl FromStr for Game {
-    type Err = &'static str;
+    type Err = SolveError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split(':').collect();
-        let id = parts[0]
+        let (header, rounds) = s
+            .split_once(':')
+            .ok_or_else(|| SolveError::parse(format!("Missing ':' in game: {:?}", s)))?;
+        let id = header
             .split(' ')
-            .last()
-            .unwrap()
-            .parse::<usize>()
-            .unwrap_or_else(|_| {
-                eprintln!("Error parsing data: {:?}", parts[0]);
-                std::process::exit(1);
-            });
-        // .unwrap();
-        let rounds: Vec<Round> = parts[1]
+            .next_back()
+            .unwrap_or_default()
+            .parse::<usize>()?;
+        let rounds = rounds
             .split(';')
-            .map(|round| round.parse::<Round>().unwrap())
-            .collect();
+            .map(|round| round.parse::<Round>())
+            .collect::<Result<Vec<Round>, _>>()?;
         Ok(Self { id, rounds })
     }
 }
-pub fn solve() -> SolutionPair {
-    // Your solution here...
+pub const INFO: DayInfo = DayInfo::new::<Day02>(2023, 2, "Cube Conundrum", &[Part::One, Part::Two]);
 
-    let input = include_str!("../../input/input02.txt");
-    let sol1 = get_part1(input);
-    let sol2 = get_part2(input);
-    (Solution::from(sol1), Solution::from(sol2))
+pub struct Day02;
+
+impl Solver for Day02 {
+    type Input<'a> = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        input.lines().map(|line| line.parse::<Game>()).collect()
+    }
+
+    fn part1(games: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        Ok(Solution::from(get_part1(games)))
+    }
+
+    fn part2(games: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        Ok(Solution::from(get_part2(games)))
+    }
 }
 
-fn get_part1(input: &str) -> usize {
+fn get_part1(games: &[Game]) -> usize {
     let num_balls = NumBalls {
         num_red: 12,
         num_green: 13,
         num_blue: 14,
     };
-    input
-        .lines()
-        .map(|line| line.parse::<Game>())
-        .filter(|game| game.as_ref().unwrap().is_valid(num_balls))
-        .map(|game| game.unwrap().id)
+    games
+        .iter()
+        .filter(|game| game.is_valid(num_balls))
+        .map(|game| game.id)
         .sum()
 }
 
-fn get_part2(input: &str) -> usize {
-    input
-        .lines()
-        .map(|line| line.parse::<Game>())
-        .map(|game| game.unwrap().get_power())
-        .sum()
+fn get_part2(games: &[Game]) -> usize {
+    games.iter().map(|game| game.get_power()).sum()
 }
 
 #[test]
@@ -172,7 +172,7 @@ fn test_valid_round() {
     };
     let round = "8 blue, 2 green, 9 red";
     let round = round.parse::<Round>();
-    assert_eq!(true, round.unwrap().valid(&num_balls));
+    assert!(round.unwrap().valid(&num_balls));
 }
 
 #[test]
@@ -184,7 +184,7 @@ fn test_valid_game() {
     };
     let line = "Game 73: 8 blue, 2 green, 9 red; 2 green, 10 red, 6 blue; 3 blue, 6 green, 2 red";
     let game = line.parse::<Game>();
-    assert_eq!(true, game.unwrap().is_valid(num_balls));
+    assert!(game.unwrap().is_valid(num_balls));
 }
 
 #[test]
@@ -196,16 +196,12 @@ fn test_invalid_game() {
     };
     let line = "Game 73: 8 blue, 2 green, 19 red; 2 green, 10 red, 6 blue; 3 blue, 6 green, 2 red";
     let game = line.parse::<Game>();
-    assert_eq!(false, game.unwrap().is_valid(num_balls));
+    assert!(!game.unwrap().is_valid(num_balls));
 }
 
 #[test]
-fn test_test_data() {
-    let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
-        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
-        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
-        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
-    let sol = get_part1(input);
-    assert_eq!(8, sol);
+fn test_malformed_game() {
+    assert!("Game 1 3 blue, 4 red".parse::<Game>().is_err());
+    assert!("Game x: 3 blue, 4 red".parse::<Game>().is_err());
+    assert!("Game 1: 3 purple".parse::<Game>().is_err());
 }
@@ -0,0 +1,174 @@
+use crate::etc::grid::Grid;
+use crate::{DayInfo, Part, Solution, SolveError, Solver};
+
+///////////////////////////////////////////////////////////////////////////////
+#[derive(Debug, PartialEq)]
+struct Number {
+    line: usize,
+    num: usize,
+    position: (usize, usize),
+}
+
+#[derive(Debug, PartialEq)]
+struct Gear {
+    line: usize,
+    position: (usize, usize),
+}
+
+fn is_symbol(c: char) -> bool {
+    !c.is_ascii_digit() && c != '.'
+}
+
+impl Gear {
+    fn check_adjacent(&self, number: &Number) -> bool {
+        if self.position.1 < number.position.0 || number.position.1 < self.position.0 {
+            return false;
+        }
+        true
+    }
+}
+
+fn find_all_gears(grid: &Grid<char>) -> Vec<Gear> {
+    grid.find_all(|&c| c == '*')
+        .map(|(line, index)| Gear {
+            line,
+            position: (index.saturating_sub(1), index + 1),
+        })
+        .collect()
+}
+
+/// Every number with the first and last column of its digits.
+fn find_all_numbers(grid: &Grid<char>) -> Vec<Number> {
+    let mut result = Vec::new();
+    for (line, row) in grid.rows().enumerate() {
+        let mut index = 0;
+        while index < row.len() {
+            if !row[index].is_ascii_digit() {
+                index += 1;
+                continue;
+            }
+            let start = index;
+            let mut num = 0;
+            while index < row.len() && row[index].is_ascii_digit() {
+                num = num * 10 + row[index].to_digit(10).unwrap_or_default() as usize;
+                index += 1;
+            }
+            result.push(Number {
+                line,
+                num,
+                position: (start, index - 1),
+            });
+        }
+    }
+    result
+}
+
+/// Whether any digit of `number` touches a symbol, diagonals included.
+fn is_part_number(grid: &Grid<char>, number: &Number) -> bool {
+    (number.position.0..=number.position.1).any(|col| {
+        grid.neighbours8((number.line, col))
+            .any(|pos| is_symbol(grid[pos]))
+    })
+}
+
+pub const INFO: DayInfo = DayInfo::new::<Day03>(2023, 3, "Gear Ratios", &[Part::One, Part::Two]);
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        Grid::parse(input, Some)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        Ok(Solution::from(solve_part1(grid)))
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        Ok(Solution::from(solve_part2(grid)))
+    }
+}
+
+fn solve_part2(grid: &Grid<char>) -> usize {
+    let mut sol: usize = 0;
+    let gears = find_all_gears(grid);
+    let nums = find_all_numbers(grid);
+    for gear in gears {
+        let mut adjacent: Vec<usize> = Vec::new();
+        let result: Vec<&Number> = nums
+            .iter()
+            .filter(|&n| {
+                n.line == gear.line
+                    || n.line == gear.line.saturating_sub(1)
+                    || (n.line == gear.line + 1)
+            })
+            .collect();
+        for num in result {
+            if gear.check_adjacent(num) {
+                adjacent.push(num.num);
+            }
+        }
+
+        if adjacent.len() == 2 {
+            sol += adjacent.first().unwrap() * adjacent.last().unwrap();
+        }
+    }
+    sol
+}
+
+fn solve_part1(grid: &Grid<char>) -> usize {
+    find_all_numbers(grid)
+        .iter()
+        .filter(|number| is_part_number(grid, number))
+        .map(|number| number.num)
+        .sum()
+}
+
+#[test]
+fn test_is_symbol() {
+    assert!(is_symbol('*'));
+}
+
+#[test]
+fn test_period_is_not_symbol() {
+    assert!(!is_symbol('.'));
+}
+
+#[test]
+fn test_digit_is_not_symbol() {
+    assert!(!is_symbol('5'));
+}
+
+#[test]
+fn test_find_all_numbers() {
+    let grid = Grid::parse("467..114..\n...*....12", Some).unwrap();
+    assert_eq!(
+        find_all_numbers(&grid),
+        vec![
+            Number {
+                line: 0,
+                num: 467,
+                position: (0, 2)
+            },
+            Number {
+                line: 0,
+                num: 114,
+                position: (5, 7)
+            },
+            Number {
+                line: 1,
+                num: 12,
+                position: (8, 9)
+            }
+        ]
+    );
+    assert!(is_part_number(&grid, &find_all_numbers(&grid)[0]));
+    assert!(!is_part_number(&grid, &find_all_numbers(&grid)[1]));
+}
+
+#[test]
+fn test_ragged_input() {
+    assert!(Day03::parse(".12\n.").is_err());
+}
@@ -0,0 +1,109 @@
+use crate::{DayInfo, Part, Solution, SolveError, Solver};
+
+///////////////////////////////////////////////////////////////////////////////
+use std::str::FromStr;
+
+#[derive(Clone)]
+pub struct LotteryCard {
+    points: usize,
+    matches: usize,
+    copies: usize,
+}
+
+impl FromStr for LotteryCard {
+    type Err = SolveError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((_, rest)) = s.split_once(':') {
+            let (winning_nums, card_nums) = rest
+                .split_once('|')
+                .ok_or_else(|| SolveError::parse(format!("No | found in card: {:?}", s)))?;
+
+            let winning_nums: Vec<usize> = winning_nums
+                .split_whitespace()
+                .map(|s| s.parse::<usize>())
+                .collect::<Result<_, _>>()?;
+
+            let card_nums: Vec<usize> = card_nums
+                .split_whitespace()
+                .map(|s| s.parse::<usize>())
+                .collect::<Result<_, _>>()?;
+
+            let matching_nums = card_nums
+                .iter()
+                .filter(|&num| winning_nums.contains(num))
+                .count();
+
+            let mut points = 0;
+            for i in 0..matching_nums {
+                points = 2usize.pow(i as u32);
+            }
+            Ok(LotteryCard {
+                points,
+                matches: matching_nums,
+                copies: 1,
+            })
+        } else {
+            Err(SolveError::parse(format!("No : found in card: {:?}", s)))
+        }
+    }
+}
+
+fn check_for_winners_and_add(mut cards: Vec<LotteryCard>) -> usize {
+    let num_cards = cards.len();
+    for index in 0..num_cards {
+        if cards[index].matches > 0 {
+            // Cards past the end of the table are not won.
+            let last_index = (index + 1 + cards[index].matches).min(num_cards);
+            for next_index in index + 1..last_index {
+                cards[next_index].copies += cards[index].copies;
+            }
+        }
+    }
+
+    cards.iter().map(|card| card.copies).sum()
+}
+
+pub const INFO: DayInfo = DayInfo::new::<Day04>(2023, 4, "Scratchcards", &[Part::One, Part::Two]);
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Input<'a> = Vec<LotteryCard>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        input
+            .lines()
+            .map(|line| line.parse::<LotteryCard>())
+            .collect()
+    }
+
+    fn part1(cards: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        Ok(Solution::from(solve_part1(cards)))
+    }
+
+    fn part2(cards: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        Ok(Solution::from(solve_part2(cards)))
+    }
+}
+
+fn solve_part1(lottery_cards: &[LotteryCard]) -> usize {
+    lottery_cards.iter().map(|card| card.points).sum()
+}
+
+fn solve_part2(lottery_cards: &[LotteryCard]) -> usize {
+    check_for_winners_and_add(lottery_cards.to_vec())
+}
+
+#[test]
+fn test_malformed_card() {
+    assert!("Card 1: 41 48 83".parse::<LotteryCard>().is_err());
+    assert!("Card 1: 41 x | 83".parse::<LotteryCard>().is_err());
+}
+
+#[test]
+fn test_wins_past_the_end() {
+    let cards = Day04::parse("Card 1: 5 | 6\nCard 2: 1 | 1").unwrap();
+    assert_eq!(2, solve_part2(&cards));
+    let cards = Day04::parse("Card 1: 1 2 | 1 2\nCard 2: 3 | 3").unwrap();
+    assert_eq!(3, solve_part2(&cards));
+}
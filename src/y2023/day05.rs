@@ -1,4 +1,4 @@
-use crate::{Solution, SolutionPair};
+use crate::{DayInfo, Part, Solution, SolveError, Solver};
 
 ///////////////////////////////////////////////////////////////////////////////
 use rayon::prelude::*;
@@ -11,7 +11,7 @@ struct Mapping {
 }
 
 #[derive(Debug)]
-struct Almanac {
+pub struct Almanac {
     seeds: Vec<usize>,
     seeds_part2: Vec<Mapping>,
     seed_to_soil: Vec<Mapping>,
@@ -39,7 +39,7 @@ impl Almanac {
     }
 }
 
-fn parse_seeds_part2(seeds: &Vec<usize>) -> Vec<Mapping> {
+fn parse_seeds_part2(seeds: &[usize]) -> Vec<Mapping> {
     let mut array: Vec<Mapping> = Vec::new();
     for part in seeds.chunks_exact(2) {
         array.push(Mapping {
@@ -51,16 +51,18 @@ fn parse_seeds_part2(seeds: &Vec<usize>) -> Vec<Mapping> {
     array
 }
 
-fn parse_input(input: &str) -> Almanac {
+fn parse_input(input: &str) -> Result<Almanac, SolveError> {
     let mut lines = input.lines();
     let mut almanac = Almanac::new();
 
-    if let Some(seeds_line) = lines.next() {
-        almanac.seeds = seeds_line
-            .split_whitespace()
-            .filter_map(|s| s.parse().ok())
-            .collect();
-    }
+    let seeds_line = lines
+        .next()
+        .and_then(|line| line.strip_prefix("seeds:"))
+        .ok_or_else(|| SolveError::parse("Missing seeds line"))?;
+    almanac.seeds = seeds_line
+        .split_whitespace()
+        .map(|s| s.parse())
+        .collect::<Result<_, _>>()?;
 
     almanac.seeds_part2 = parse_seeds_part2(&almanac.seeds);
 
@@ -86,13 +88,13 @@ fn parse_input(input: &str) -> Almanac {
     lines.next();
     almanac.humidity_to_location = parse_map(&mut lines);
 
-    almanac
+    Ok(almanac)
 }
 
 fn parse_map(lines: &mut std::str::Lines) -> Vec<Mapping> {
     let mut list: Vec<Mapping> = Vec::new();
 
-    while let Some(line) = lines.next() {
+    for line in lines.by_ref() {
         let numbers: Vec<usize> = line
             .split_whitespace()
             .filter_map(|s| s.parse().ok())
@@ -136,32 +138,36 @@ fn map_seed2_range_to_min_location(seeds_part2: &Mapping, almanac: &Almanac) ->
         let val = map_seed_to_location(seed_val, almanac);
         min_loc = usize::min(min_loc, val);
     }
-    println!(
-        "Seed block: {} --> {}, Min Location is {}",
-        seeds_part2.source,
-        seeds_part2.source + seeds_part2.len,
-        min_loc
-    );
     min_loc
 }
 
-pub fn solve() -> SolutionPair {
-    // Your solution here...
-    let input = include_str!("../../input/input05.txt");
-    let almanac = parse_input(input);
-    let sol1 = solve_part1(&almanac);
-    let sol2 = solve_part2(&almanac);
+pub const INFO: DayInfo = DayInfo::new::<Day05>(2023, 5, "If You Give A Seed A Fertilizer", &[Part::One, Part::Two]);
+
+pub struct Day05;
 
-    (Solution::from(sol1), Solution::from(sol2))
+impl Solver for Day05 {
+    type Input<'a> = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        parse_input(input)
+    }
+
+    fn part1(almanac: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        Ok(Solution::from(solve_part1(almanac)?))
+    }
+
+    fn part2(almanac: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        Ok(Solution::from(solve_part2(almanac)))
+    }
 }
 
-fn solve_part1(almanac: &Almanac) -> usize {
+fn solve_part1(almanac: &Almanac) -> Result<usize, SolveError> {
     almanac
         .seeds
         .iter()
         .map(|seed| map_seed_to_location(*seed, almanac))
         .min()
-        .unwrap()
+        .ok_or_else(|| SolveError::no_answer("No seeds in almanac"))
 }
 
 fn solve_part2(almanac: &Almanac) -> usize {
@@ -169,34 +175,13 @@ fn solve_part2(almanac: &Almanac) -> usize {
         .seeds_part2
         .par_iter()
         .map(|seed| map_seed2_range_to_min_location(seed, almanac))
-        .reduce(|| usize::MAX, |a, b| usize::min(a, b))
-}
-
-#[test]
-fn test_solve_part1() {
-    let input = include_str!("../../input/input05_test.txt");
-    let almanac = parse_input(input);
-    assert_eq!(35, solve_part1(&almanac));
-}
-
-#[test]
-fn test_solve_part1_real() {
-    let input = include_str!("../../input/input05.txt");
-    let almanac = parse_input(input);
-    assert_eq!(486613012, solve_part1(&almanac));
-}
-
-#[test]
-fn test_solve_part2() {
-    let input = include_str!("../../input/input05_test.txt");
-    let almanac = parse_input(input);
-    assert_eq!(46, solve_part2(&almanac));
+        .reduce(|| usize::MAX, usize::min)
 }
 
 #[test]
 fn test_seed_to_soil() {
-    let input = include_str!("../../input/input05_test.txt");
-    let almanac = parse_input(input);
+    let input = include_str!("../../input/2023/examples/day05_1.txt");
+    let almanac = parse_input(input).unwrap();
     assert_eq!(10, transform_source_to_dest(10, &almanac.seed_to_soil));
 
     assert_eq!(57, transform_source_to_dest(55, &almanac.seed_to_soil));
@@ -205,10 +190,15 @@ fn test_seed_to_soil() {
 
 #[test]
 fn test_seed_to_location() {
-    let input = include_str!("../../input/input05_test.txt");
-    let almanac = parse_input(input);
+    let input = include_str!("../../input/2023/examples/day05_1.txt");
+    let almanac = parse_input(input).unwrap();
     assert_eq!(82, map_seed_to_location(79, &almanac));
     assert_eq!(43, map_seed_to_location(14, &almanac));
     assert_eq!(86, map_seed_to_location(55, &almanac));
     assert_eq!(35, map_seed_to_location(13, &almanac));
 }
+
+#[test]
+fn test_missing_seeds() {
+    assert!(parse_input("seed-to-soil map:\n50 98 2").is_err());
+}
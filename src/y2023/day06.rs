@@ -0,0 +1,159 @@
+use crate::{DayInfo, Part, Solution, SolveError, Solver};
+
+///////////////////////////////////////////////////////////////////////////////
+use regex::Regex;
+
+#[derive(Debug, PartialEq)]
+pub struct Race {
+    time: usize,
+    distance: usize,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Race>, SolveError> {
+    let mut races: Vec<Race> = Vec::new();
+
+    let time_regex = Regex::new(r"\d+").unwrap();
+    let distance_regex = Regex::new(r"\d+").unwrap();
+
+    let time_values: Vec<usize> = time_regex
+        .captures_iter(input_line(input, 0)?)
+        .map(|capture| capture[0].parse())
+        .collect::<Result<_, _>>()?;
+
+    let distance_values: Vec<usize> = distance_regex
+        .captures_iter(input_line(input, 1)?)
+        .map(|capture| capture[0].parse())
+        .collect::<Result<_, _>>()?;
+
+    if time_values.len() != distance_values.len() {
+        return Err(SolveError::parse("Number of times and distances differ"));
+    }
+
+    for (time, distance) in time_values.into_iter().zip(distance_values) {
+        let race = Race { time, distance };
+        races.push(race);
+    }
+
+    Ok(races)
+}
+
+fn parse_input_2(input: &str) -> Result<Race, SolveError> {
+    let time_line = input_line(input, 0)?;
+    let distance_line = input_line(input, 1)?;
+
+    let time: usize = joined_number(time_line)?;
+    let distance: usize = joined_number(distance_line)?;
+
+    Ok(Race { time, distance })
+}
+
+fn input_line(input: &str, index: usize) -> Result<&str, SolveError> {
+    input
+        .lines()
+        .nth(index)
+        .ok_or_else(|| SolveError::parse(format!("Missing line {}", index + 1)))
+}
+
+fn joined_number(line: &str) -> Result<usize, SolveError> {
+    let (_, values) = line
+        .split_once(':')
+        .ok_or_else(|| SolveError::parse(format!("Missing ':' in {:?}", line)))?;
+    Ok(values.replace(' ', "").parse()?)
+}
+
+fn calc_num_possible_wins(race: &Race) -> usize {
+    let mut counter = 0;
+    for hold_time in 0..race.time {
+        if hold_time * (race.time - hold_time) > race.distance {
+            counter += 1;
+        }
+    }
+    counter
+}
+
+fn solve_part1(races: &[Race]) -> usize {
+    races
+        .iter()
+        .map(calc_num_possible_wins)
+        .product()
+}
+
+fn solve_part2(race: &Race) -> usize {
+    calc_num_possible_wins(race)
+}
+
+pub const INFO: DayInfo = DayInfo::new::<Day06>(2023, 6, "Wait For It", &[Part::One, Part::Two]);
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    type Input<'a> = (Vec<Race>, Race);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        Ok((parse_input(input)?, parse_input_2(input)?))
+    }
+
+    fn part1((races, _): &Self::Input<'_>) -> Result<Solution, SolveError> {
+        Ok(Solution::from(solve_part1(races)))
+    }
+
+    fn part2((_, race): &Self::Input<'_>) -> Result<Solution, SolveError> {
+        Ok(Solution::from(solve_part2(race)))
+    }
+}
+
+#[test]
+fn test_parsing() {
+    let input = r"Time:        49     97     94     94
+        Distance:   263   1532   1378   1851
+    ";
+    let expected_result = vec![
+        Race {
+            time: 49,
+            distance: 263,
+        },
+        Race {
+            time: 97,
+            distance: 1532,
+        },
+        Race {
+            time: 94,
+            distance: 1378,
+        },
+        Race {
+            time: 94,
+            distance: 1851,
+        },
+    ];
+
+    assert_eq!(parse_input(input).unwrap(), expected_result);
+}
+
+#[test]
+fn test_calc_num_possible_wins() {
+    let race = Race {
+        time: 7,
+        distance: 9,
+    };
+    assert_eq!(4, calc_num_possible_wins(&race));
+}
+
+#[test]
+fn test_parse_input_2() {
+    let input = "Time:      7  15   30
+Distance:  9  40  200";
+
+    let expected_result = Race {
+        time: 71530,
+        distance: 940200,
+    };
+
+    assert_eq!(parse_input_2(input).unwrap(), expected_result);
+}
+
+#[test]
+fn test_missing_distance_line() {
+    let input = "Time:      7  15   30";
+    assert!(parse_input(input).is_err());
+    assert!(parse_input_2(input).is_err());
+}
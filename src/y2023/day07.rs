@@ -0,0 +1,209 @@
+use crate::{DayInfo, Part, Solution, SolveError, Solver};
+
+///////////////////////////////////////////////////////////////////////////////
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct Hand<'a> {
+    cards: &'a str,
+    bid: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeofAKind,
+    FullHouse,
+    FourofAKind,
+    FiveofAKind,
+}
+
+/// The rules of a Camel Cards variant.
+struct Ruleset {
+    /// Card labels from weakest to strongest.
+    order: &'static str,
+    /// Cards that count as whatever makes the hand strongest, while ranking
+    /// by their place in `order` on ties.
+    wildcards: &'static str,
+    /// Hand type from the group sizes, largest first, once the wildcards
+    /// have joined the largest group.
+    classify: fn(&[usize]) -> HandType,
+}
+
+const STANDARD: Ruleset = Ruleset {
+    order: "23456789TJQKA",
+    wildcards: "",
+    classify,
+};
+
+/// Part 2: `J` is a joker, the weakest card on its own.
+const JOKERS: Ruleset = Ruleset {
+    order: "J23456789TQKA",
+    wildcards: "J",
+    classify,
+};
+
+fn classify(counts: &[usize]) -> HandType {
+    match counts {
+        [5, ..] => HandType::FiveofAKind,
+        [4, ..] => HandType::FourofAKind,
+        [3, 2, ..] => HandType::FullHouse,
+        [3, ..] => HandType::ThreeofAKind,
+        [2, 2, ..] => HandType::TwoPair,
+        [2, ..] => HandType::OnePair,
+        _ => HandType::HighCard,
+    }
+}
+
+impl Ruleset {
+    /// Rank of a card, higher is stronger.
+    fn parse_card(&self, c: char) -> Result<usize, SolveError> {
+        let c = c.to_ascii_uppercase();
+        self.order
+            .chars()
+            .position(|card| card == c)
+            .ok_or_else(|| SolveError::parse(format!("Unknown card {:?}", c)))
+    }
+
+    fn hand_type(&self, cards: &str) -> HandType {
+        let mut card_counts: HashMap<char, usize> = HashMap::new();
+        let mut wildcards = 0;
+
+        for card in cards.chars().map(|c| c.to_ascii_uppercase()) {
+            if self.wildcards.contains(card) {
+                wildcards += 1;
+            } else {
+                *card_counts.entry(card).or_insert(0) += 1;
+            }
+        }
+
+        let mut counts: Vec<usize> = card_counts.into_values().collect();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        // The wildcards always do best joining the largest group.
+        match counts.first_mut() {
+            Some(largest) => *largest += wildcards,
+            None => counts.push(wildcards),
+        }
+        (self.classify)(&counts)
+    }
+
+    /// Sort key of a hand: its type, then its cards from first to last.
+    fn strength(&self, cards: &str) -> Result<(HandType, Vec<usize>), SolveError> {
+        let ranks = cards
+            .chars()
+            .map(|c| self.parse_card(c))
+            .collect::<Result<_, _>>()?;
+        Ok((self.hand_type(cards), ranks))
+    }
+
+    fn total_winnings(&self, hands: &[Hand]) -> Result<usize, SolveError> {
+        let mut ranked = hands
+            .iter()
+            .map(|hand| Ok((self.strength(hand.cards)?, hand.bid)))
+            .collect::<Result<Vec<_>, SolveError>>()?;
+        ranked.sort_unstable();
+        Ok(ranked
+            .iter()
+            .enumerate()
+            .map(|(index, (_, bid))| (index + 1) * bid)
+            .sum())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Hand<'_>>, SolveError> {
+    let mut hands = Vec::new();
+    for line in input.lines() {
+        let (cards, bid) = line
+            .split_once(' ')
+            .ok_or_else(|| SolveError::parse(format!("No bid in hand: {:?}", line)))?;
+        if cards.chars().count() != 5 {
+            return Err(SolveError::parse(format!("Hand {:?} does not have 5 cards", cards)));
+        }
+        // Every variant uses the same labels, so any ruleset can check them.
+        for card in cards.chars() {
+            STANDARD.parse_card(card)?;
+        }
+        let bid = bid.parse::<usize>()?;
+        hands.push(Hand { cards, bid })
+    }
+    Ok(hands)
+}
+
+pub const INFO: DayInfo = DayInfo::new::<Day07>(2023, 7, "Camel Cards", &[Part::One, Part::Two]);
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    type Input<'a> = Vec<Hand<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        parse_input(input)
+    }
+
+    fn part1(hands: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        Ok(Solution::from(STANDARD.total_winnings(hands)?))
+    }
+
+    fn part2(hands: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        Ok(Solution::from(JOKERS.total_winnings(hands)?))
+    }
+}
+
+#[test]
+fn test_parse() {
+    let input = "32T3K 765";
+    println!("{:?}", parse_input(input));
+}
+
+#[test]
+fn test_invalid_bid() {
+    assert!(parse_input("32T3K abc").is_err());
+}
+
+#[test]
+fn test_jokers() {
+    let result = |hand: &str| JOKERS.hand_type(hand);
+    assert_eq!(HandType::OnePair, result("32T3K"));
+    assert_eq!(HandType::FourofAKind, result("T55J5"));
+    assert_eq!(HandType::FourofAKind, result("KTJJT"));
+    assert_eq!(HandType::FullHouse, result("2233J"));
+    assert_eq!(HandType::FiveofAKind, result("JJJJJ"));
+    assert_eq!(HandType::OnePair, result("2345J"));
+    assert_eq!(HandType::TwoPair, STANDARD.hand_type("KTJJT"));
+    // The joker is the weakest card.
+    assert!(JOKERS.parse_card('J').unwrap() < JOKERS.parse_card('2').unwrap());
+}
+
+#[test]
+fn test_unknown_card() {
+    assert!(STANDARD.parse_card('X').is_err());
+    assert!(parse_input("32T3X 765").is_err());
+}
+
+#[test]
+fn test_malformed_hand() {
+    assert!(parse_input("garbage").is_err());
+    assert!(parse_input("32T3 765").is_err());
+    assert!(parse_input("32T3KK 765").is_err());
+    assert!(parse_input("32T3K 765\nKK677").is_err());
+}
+
+#[test]
+fn test_variants() {
+    // Two wildcards, and the aces ranked lowest.
+    let rules = Ruleset {
+        order: "AJQ23456789TK",
+        wildcards: "JQ",
+        classify,
+    };
+    assert_eq!(HandType::FiveofAKind, rules.hand_type("JQ222"));
+    assert_eq!(HandType::FourofAKind, rules.hand_type("Q2J3J"));
+    assert_eq!(HandType::FullHouse, STANDARD.hand_type("QQ222"));
+    assert!(rules.parse_card('A').unwrap() < rules.parse_card('Q').unwrap());
+
+    let hands = parse_input("A2222 1\n32222 2").unwrap();
+    assert_eq!(5, rules.total_winnings(&hands).unwrap());
+    assert_eq!(4, STANDARD.total_winnings(&hands).unwrap());
+}
@@ -0,0 +1,326 @@
+use crate::{DayInfo, Part, Solution, SolveError, Solver};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{alphanumeric1, char, line_ending, multispace0, multispace1};
+use nom::combinator::value;
+use nom::multi::{many1, separated_list1};
+use nom::sequence::{delimited, separated_pair, terminated};
+use nom::IResult;
+use nom_supreme::error::ErrorTree;
+use nom_supreme::final_parser::{final_parser, Location};
+use std::collections::HashMap;
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Direction {
+    Left,
+    Right,
+}
+
+/// The network with every node name interned to its index, so a step is a
+/// lookup in `links`.
+#[derive(Debug)]
+pub struct Map<'a> {
+    directions: Vec<Direction>,
+    names: Vec<&'a str>,
+    /// Left and right neighbour of every node.
+    links: Vec<[usize; 2]>,
+    index: HashMap<&'a str, usize>,
+}
+
+impl<'a> Map<'a> {
+    fn node(&self, name: &str) -> Result<usize, SolveError> {
+        self.index
+            .get(name)
+            .copied()
+            .ok_or_else(|| SolveError::parse(format!("Unknown node '{}'", name)))
+    }
+
+    fn step(&self, node: usize, direction: Direction) -> usize {
+        self.links[node][direction as usize]
+    }
+}
+
+type Entry<'a> = (&'a str, (&'a str, &'a str));
+
+fn parse_directions(input: &str) -> IResult<&str, Vec<Direction>, ErrorTree<&str>> {
+    many1(alt((
+        value(Direction::Left, char('L')),
+        value(Direction::Right, char('R')),
+    )))(input)
+}
+
+/// `AAA = (BBB, CCC)`
+fn parse_entry(input: &str) -> IResult<&str, Entry<'_>, ErrorTree<&str>> {
+    separated_pair(
+        alphanumeric1,
+        tag(" = "),
+        delimited(
+            char('('),
+            separated_pair(alphanumeric1, tag(", "), alphanumeric1),
+            char(')'),
+        ),
+    )(input)
+}
+
+fn parse_document(input: &str) -> IResult<&str, (Vec<Direction>, Vec<Entry<'_>>), ErrorTree<&str>> {
+    terminated(
+        separated_pair(parse_directions, multispace1, separated_list1(line_ending, parse_entry)),
+        multispace0,
+    )(input)
+}
+
+fn parse_input(input: &str) -> Result<Map<'_>, SolveError> {
+    let (directions, entries) = final_parser(parse_document)(input)
+        .map_err(|err: ErrorTree<Location>| SolveError::parse(err.to_string()))?;
+
+    let names: Vec<&str> = entries.iter().map(|&(name, _)| name).collect();
+    let mut index = HashMap::new();
+    for (i, &name) in names.iter().enumerate() {
+        if index.insert(name, i).is_some() {
+            return Err(SolveError::parse(format!("Node '{}' is defined twice", name)));
+        }
+    }
+    let mut map = Map {
+        directions,
+        names,
+        links: Vec::new(),
+        index,
+    };
+    map.links = entries
+        .iter()
+        .map(|&(_, (left, right))| Ok([map.node(left)?, map.node(right)?]))
+        .collect::<Result<_, SolveError>>()?;
+    Ok(map)
+}
+
+fn traverse_map(map: &Map) -> Result<usize, SolveError> {
+    let mut location = map.node("AAA")?;
+    let end = map.node("ZZZ")?;
+    // Once a state, node and position in the directions, repeats without
+    // having reached the end, the walk is in a loop that never does.
+    let len = map.directions.len();
+    let mut seen = vec![false; map.names.len() * len];
+    let mut steps = 0;
+    loop {
+        let index = steps % len;
+        let state = location * len + index;
+        if seen[state] {
+            return Err(SolveError::no_answer("ZZZ cannot be reached from AAA"));
+        }
+        seen[state] = true;
+        location = map.step(location, map.directions[index]);
+        steps += 1;
+        if location == end {
+            return Ok(steps);
+        }
+    }
+}
+
+/// The steps at which a ghost stands on a node ending in `Z`: `start`, then
+/// every `period` steps after it, or only `start` if `period` is zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Hits {
+    start: u64,
+    period: u64,
+}
+
+impl Hits {
+    fn contains(&self, step: u64) -> bool {
+        match self.period {
+            0 => step == self.start,
+            period => step >= self.start && (step - self.start).is_multiple_of(period),
+        }
+    }
+
+    /// The steps in both, found with the Chinese remainder theorem.
+    fn intersect(&self, other: &Hits) -> Option<Hits> {
+        match (self.period, other.period) {
+            (0, _) => other.contains(self.start).then_some(*self),
+            (_, 0) => self.contains(other.start).then_some(*other),
+            (p, q) => {
+                let (x, period) = crt(self.start % p, p, other.start % q, q)?;
+                // The first common step at or after both starts.
+                let min = self.start.max(other.start);
+                let start = x + min.saturating_sub(x).div_ceil(period) * period;
+                Some(Hits { start, period })
+            }
+        }
+    }
+}
+
+/// Extended Euclid: `(g, x, y)` with `a * x + b * y == g == gcd(a, b)`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - (a / b) * y)
+    }
+}
+
+/// The `x` below `lcm(p, q)` with `x ≡ a (mod p)` and `x ≡ b (mod q)`, and
+/// `lcm(p, q)`; `None` if there is none. The periods need not be coprime.
+fn crt(a: u64, p: u64, b: u64, q: u64) -> Option<(u64, u64)> {
+    let (a, p, b, q) = (a as i128, p as i128, b as i128, q as i128);
+    let (g, inv, _) = extended_gcd(p, q);
+    if (b - a) % g != 0 {
+        return None;
+    }
+    let lcm = p / g * q;
+    let k = ((b - a) / g * inv).rem_euclid(q / g);
+    let x = (a + p * k).rem_euclid(lcm);
+    Some((x as u64, u64::try_from(lcm).ok()?))
+}
+
+/// Walks one ghost until its state, node and position in the directions,
+/// repeats. Every visit to a `Z` node before the cycle happens once; those
+/// inside the cycle repeat with its length.
+fn ghost_hits(map: &Map, start: usize) -> Result<Vec<Hits>, SolveError> {
+    let len = map.directions.len();
+    let mut seen: Vec<Option<u64>> = vec![None; map.names.len() * len];
+    let mut z_steps = Vec::new();
+    let mut location = start;
+    let mut step = 0;
+    let (cycle_start, period) = loop {
+        let index = (step % len as u64) as usize;
+        let state = location * len + index;
+        if let Some(first) = seen[state] {
+            break (first, step - first);
+        }
+        seen[state] = Some(step);
+        if map.names[location].ends_with('Z') {
+            z_steps.push(step);
+        }
+        location = map.step(location, map.directions[index]);
+        step += 1;
+    };
+
+    let hits: Vec<Hits> = z_steps
+        .into_iter()
+        .map(|start| Hits {
+            start,
+            period: if start < cycle_start { 0 } else { period },
+        })
+        .collect();
+    if hits.is_empty() {
+        return Err(SolveError::no_answer(format!(
+            "The ghost starting at {} never reaches a node ending in Z",
+            map.names[start]
+        )));
+    }
+    Ok(hits)
+}
+
+/// Every ghost moves in a cycle, so the steps at which all of them stand on a
+/// `Z` node are the intersection of their hits. In the usual input each ghost
+/// reaches one `Z` node exactly at the end of each cycle and this is the LCM
+/// of the cycle lengths, but the cycles are checked rather than assumed.
+fn traverse_ghosts(map: &Map) -> Result<u64, SolveError> {
+    let starts = (0..map.names.len()).filter(|&node| map.names[node].ends_with('A'));
+
+    let mut common: Option<Vec<Hits>> = None;
+    for start in starts {
+        let hits = ghost_hits(map, start)?;
+        common = Some(match common {
+            None => hits,
+            Some(common) => common
+                .iter()
+                .flat_map(|a| hits.iter().filter_map(move |b| a.intersect(b)))
+                .collect(),
+        });
+    }
+    let common = common.ok_or_else(|| SolveError::parse("No nodes ending in A"))?;
+    common
+        .iter()
+        .map(|hits| hits.start)
+        .min()
+        .ok_or_else(|| SolveError::no_answer("The ghosts never all stand on nodes ending in Z at once"))
+}
+
+pub const INFO: DayInfo = DayInfo::new::<Day08>(2023, 8, "Haunted Wasteland", &[Part::One, Part::Two]);
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    type Input<'a> = Map<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        parse_input(input)
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        Ok(Solution::from(solve_part1(map)?))
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        Ok(Solution::from(traverse_ghosts(map)?))
+    }
+}
+
+fn solve_part1(map: &Map) -> Result<usize, SolveError> {
+    traverse_map(map)
+}
+
+#[test]
+fn test_missing_entry() {
+    let input = "L
+
+AAA = (BBB, BBB)";
+    let err = parse_input(input).unwrap_err();
+    assert!(err.to_string().contains("Unknown node 'BBB'"));
+}
+
+#[test]
+fn test_parse_errors() {
+    assert!(parse_input("LX\n\nAAA = (AAA, AAA)").is_err());
+    assert!(parse_input("L\n\nAAA = (AAA AAA)").is_err());
+    assert!(parse_input("L\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)").is_err());
+    let map = parse_input("RL\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+    assert_eq!(vec![Direction::Right, Direction::Left], map.directions);
+    assert_eq!([1, 2], map.links[0]);
+}
+
+#[test]
+fn test_unreachable_end() {
+    let map = parse_input("L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+    assert!(matches!(traverse_map(&map), Err(SolveError::NoAnswer(_))));
+    let map = parse_input("L\n\nAAA = (AAA, AAA)").unwrap();
+    assert!(traverse_map(&map).is_err());
+}
+
+#[test]
+fn test_crt() {
+    assert_eq!(Some((11, 12)), crt(3, 4, 5, 6));
+    assert_eq!(None, crt(1, 4, 2, 6));
+    assert_eq!(Some((0, 15)), crt(0, 3, 0, 5));
+    let a = Hits { start: 2, period: 2 };
+    let b = Hits { start: 3, period: 3 };
+    assert_eq!(Some(Hits { start: 6, period: 6 }), a.intersect(&b));
+    assert_eq!(None, a.intersect(&Hits { start: 3, period: 0 }));
+}
+
+#[test]
+fn test_ghosts_out_of_step() {
+    // Both ghosts reach Z every other step, but never on the same one.
+    let input = "L
+
+11A = (11Z, 11Z)
+11Z = (11A, 11A)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22B, 22B)";
+    let map = parse_input(input).unwrap();
+    assert!(matches!(traverse_ghosts(&map), Err(SolveError::NoAnswer(_))));
+}
+
+#[test]
+fn test_ghost_never_finishes() {
+    let input = "L
+
+11A = (11B, 11B)
+11B = (11A, 11A)";
+    let map = parse_input(input).unwrap();
+    assert!(traverse_ghosts(&map).is_err());
+}
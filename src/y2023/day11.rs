@@ -0,0 +1,131 @@
+use crate::etc::geometry::Point2;
+use crate::etc::grid::Grid;
+use crate::{DayInfo, Part, Solution, SolveError, Solver};
+use std::collections::HashSet;
+
+///////////////////////////////////////////////////////////////////////////////
+
+type GalaxyPos = Point2<usize>;
+
+fn parse_input(input: &str) -> Result<Grid<bool>, SolveError> {
+    Grid::parse(input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+fn get_galaxies(input: Grid<bool>) -> Vec<GalaxyPos> {
+    input
+        .find_all(|&galaxy| galaxy)
+        .map(|(y, x)| GalaxyPos::new(x, y))
+        .collect()
+}
+
+fn expand_galaxy(
+    galaxy: &GalaxyPos,
+    empty_rows: &HashSet<usize>,
+    empty_columns: &HashSet<usize>,
+    size: usize,
+) -> GalaxyPos {
+    GalaxyPos::new(
+        galaxy.x + size * empty_rows.iter().filter(|p| p < &&galaxy.x).count(),
+        galaxy.y + size * empty_columns.iter().filter(|p| p < &&galaxy.y).count(),
+    )
+}
+
+fn calc_all_distances(galaxies: Vec<GalaxyPos>) -> usize {
+    let mut total = 0;
+    for (i, gal) in galaxies.iter().enumerate() {
+        for other in &galaxies[..i] {
+            total += gal.manhattan(*other);
+        }
+    }
+    total
+}
+
+pub struct Universe {
+    galaxies: Vec<GalaxyPos>,
+    empty_rows: HashSet<usize>,
+    empty_columns: HashSet<usize>,
+}
+
+impl Universe {
+    fn total_distance(&self, size: usize) -> usize {
+        calc_all_distances(
+            self.galaxies
+                .iter()
+                .map(|galaxy| expand_galaxy(galaxy, &self.empty_rows, &self.empty_columns, size))
+                .collect(),
+        )
+    }
+}
+
+pub const INFO: DayInfo = DayInfo::new::<Day11>(2023, 11, "Cosmic Expansion", &[Part::One, Part::Two]);
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input<'a> = Universe;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        let parsed_input = parse_input(input)?;
+        let galaxies = get_galaxies(parsed_input);
+        if galaxies.is_empty() {
+            return Err(SolveError::no_answer("No galaxies in input"));
+        }
+
+        let columns: HashSet<_> = galaxies.iter().map(|p| p.y).collect();
+        let max_column = columns.iter().max().copied().unwrap_or_default();
+        let empty_columns: HashSet<_> = (0..max_column)
+            .filter(|c| !columns.contains(c))
+            .collect();
+
+        let rows: HashSet<_> = galaxies.iter().map(|p| p.x).collect();
+        let max_row = rows.iter().max().copied().unwrap_or_default();
+        let empty_rows: HashSet<_> = (0..max_row)
+            .filter(|c| !rows.contains(c))
+            .collect();
+
+        Ok(Universe {
+            galaxies,
+            empty_rows,
+            empty_columns,
+        })
+    }
+
+    fn part1(universe: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        Ok(Solution::from(universe.total_distance(1)))
+    }
+
+    fn part2(universe: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        Ok(Solution::from(universe.total_distance(999999)))
+    }
+}
+
+#[test]
+fn test_parse_example_1() {
+    let input = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+    let data = parse_input(input).unwrap();
+    let galaxies = get_galaxies(data);
+    let columns: HashSet<_> = galaxies.iter().map(|p| p.y).collect();
+    let empty_columns: HashSet<_> = (0..*columns.iter().max().unwrap())
+        .filter(|c| !columns.contains(c))
+        .collect();
+
+    let rows: HashSet<_> = galaxies.iter().map(|p| p.x).collect();
+    let empty_rows: HashSet<_> = (0..*rows.iter().max().unwrap())
+        .filter(|c| !rows.contains(c))
+        .collect();
+    println!("{empty_columns:?}");
+    println!("{empty_rows:?}");
+}
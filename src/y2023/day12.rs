@@ -0,0 +1,109 @@
+use crate::{DayInfo, Part, Solution, SolveError, Solver};
+
+///////////////////////////////////////////////////////////////////////////////
+use itertools::Itertools;
+use rayon::prelude::*;
+
+fn parse_input(input: &str) -> Result<(&str, Vec<usize>), SolveError> {
+    let (springs, list) = input
+        .split_once(' ')
+        .ok_or_else(|| SolveError::parse(format!("Invalid record: {:?}", input)))?;
+
+    let list = list
+        .split(',')
+        .map(|s| s.parse::<usize>())
+        .collect::<Result<_, _>>()?;
+
+    Ok((springs, list))
+}
+
+/// Number of ways to fill in the `?`s so the runs of `#` match `nums`.
+/// `dp[i]` counts the ways the first `i` springs fit the groups placed so far.
+fn count_arrangements(springs: &str, nums: &[usize]) -> u64 {
+    let springs = format!(".{}", springs.trim_end_matches('.'));
+    let springs = springs.chars().collect_vec();
+
+    let mut dp: Vec<u64> = vec![0; springs.len() + 1];
+    dp[0] = 1;
+
+    for (i, _) in springs.iter().take_while(|&&c| c != '#').enumerate() {
+        dp[i + 1] = 1;
+    }
+
+    for &num in nums {
+        let mut n_dp: Vec<u64> = vec![0; springs.len() + 1];
+        let mut chunk = 0;
+
+        for (i, &c) in springs.iter().enumerate() {
+            if c != '.' {
+                chunk += 1;
+            } else {
+                chunk = 0;
+            }
+
+            if c != '#' {
+                n_dp[i + 1] += n_dp[i];
+            }
+
+            if chunk >= num && springs[i - num] != '#' {
+                n_dp[i + 1] += dp[i - num];
+            }
+        }
+
+        dp = n_dp;
+    }
+
+    *dp.last().unwrap()
+}
+
+/// Part 2: the springs five times joined by `?`, and the groups five times.
+fn unfold(springs: &str, nums: &[usize]) -> (String, Vec<usize>) {
+    let springs = [springs; 5].join("?");
+    let nums = nums.repeat(5);
+    (springs, nums)
+}
+
+pub const INFO: DayInfo = DayInfo::new::<Day12>(2023, 12, "Hot Springs", &[Part::One, Part::Two]);
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input<'a> = Vec<(&'a str, Vec<usize>)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        input.lines().map(parse_input).collect()
+    }
+
+    fn part1(records: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        let sol1: u64 = records
+            .par_iter()
+            .map(|(springs, nums)| count_arrangements(springs, nums))
+            .sum();
+        Ok(Solution::from(sol1))
+    }
+
+    fn part2(records: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        let sol2: u64 = records
+            .par_iter()
+            .map(|(springs, nums)| {
+                let (springs, nums) = unfold(springs, nums);
+                count_arrangements(&springs, &nums)
+            })
+            .sum();
+        Ok(Solution::from(sol2))
+    }
+}
+
+#[test]
+fn test_count_arrangements() {
+    assert_eq!(1, count_arrangements("???.###", &[1, 1, 3]));
+    assert_eq!(10, count_arrangements("?###????????", &[3, 2, 1]));
+    assert_eq!(0, count_arrangements("#.#", &[1]));
+}
+
+#[test]
+fn test_unfold() {
+    assert_eq!((".#?.#?.#?.#?.#".to_string(), vec![1; 5]), unfold(".#", &[1]));
+    let (springs, nums) = unfold("?###????????", &[3, 2, 1]);
+    assert_eq!(506250, count_arrangements(&springs, &nums));
+}
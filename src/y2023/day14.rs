@@ -0,0 +1,26 @@
+use crate::{DayInfo, Solution, SolveError, Solver};
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub const INFO: DayInfo = DayInfo::new::<Day14>(2023, 14, "Parabolic Reflector Dish", &[]);
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolveError> {
+        // Your solution here...
+        Ok(input)
+    }
+
+    fn part1(_input: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        let sol1: u64 = 0;
+        Ok(Solution::from(sol1))
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Result<Solution, SolveError> {
+        let sol2: u64 = 0;
+        Ok(Solution::from(sol2))
+    }
+}
@@ -0,0 +1,17 @@
+crate::days! {
+    day01, day02, day03, day04, day05,
+    day06, day07, day08, day09, day10,
+    day11, day12, day13, day14, day15,
+    day16, day17, day18, day19, day20,
+    day21, day22, day23, day24, day25,
+}
+
+#[test]
+fn test_days() {
+    for (index, info) in DAYS.iter().enumerate() {
+        assert_eq!(index + 1, info.day as usize);
+        assert_eq!(2023, info.year);
+    }
+    assert!(!DAYS[4].is_stub());
+    assert!(DAYS[24].is_stub());
+}